@@ -0,0 +1,377 @@
+use crate::comparison::RankChange;
+use crate::summary::Summary;
+use crate::test_record::TestRecord;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Baseline {
+    tests: Vec<TestRecord>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    pub description: String,
+    pub before: T,
+    pub after: T,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Regressions {
+    newly_failing: Vec<Change<Summary>>,
+    newly_passing: Vec<Change<Summary>>,
+    rank_improved: Vec<Change<Option<usize>>>,
+    rank_regressed: Vec<Change<Option<usize>>>,
+    latency_regressed: Vec<Change<u128>>,
+    failing_without_baseline: Vec<String>,
+}
+
+impl Baseline {
+    pub fn new(tests: Vec<TestRecord>) -> Self {
+        Self { tests }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Error reading baseline {}", path.display()))?;
+        serde_json::from_str(json.as_str())
+            .with_context(|| format!("Failed to parse baseline {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Error writing baseline {}", path.display()))
+    }
+
+    pub fn regressions(&self, current: &[TestRecord], latency_threshold: u128) -> Regressions {
+        let mut regressions = Regressions::default();
+        for after in current {
+            if let Some(before) = self
+                .tests
+                .iter()
+                .find(|before| before.description == after.description)
+            {
+                regressions.add(before, after, latency_threshold);
+            } else if after.summary != Summary::Passed {
+                regressions
+                    .failing_without_baseline
+                    .push(after.description.clone());
+            }
+        }
+        regressions
+    }
+}
+
+impl Regressions {
+    pub fn passed(&self) -> bool {
+        self.newly_failing.is_empty()
+            && self.rank_regressed.is_empty()
+            && self.latency_regressed.is_empty()
+            && self.failing_without_baseline.is_empty()
+    }
+
+    fn add(&mut self, before: &TestRecord, after: &TestRecord, latency_threshold: u128) {
+        let passed_before = before.summary == Summary::Passed;
+        let passed_after = after.summary == Summary::Passed;
+        if passed_before && !passed_after {
+            self.newly_failing
+                .push(Self::change(after, &before.summary, &after.summary));
+        }
+        if !passed_before && passed_after {
+            self.newly_passing
+                .push(Self::change(after, &before.summary, &after.summary));
+        }
+
+        let rank_change = RankChange {
+            from: before.rank,
+            to: after.rank,
+        };
+        if rank_change.improved() {
+            self.rank_improved
+                .push(Self::change(after, &before.rank, &after.rank));
+        }
+        if rank_change.regressed() {
+            self.rank_regressed
+                .push(Self::change(after, &before.rank, &after.rank));
+        }
+
+        if after.elapsed_ms > before.elapsed_ms + latency_threshold {
+            self.latency_regressed
+                .push(Self::change(after, &before.elapsed_ms, &after.elapsed_ms));
+        }
+    }
+
+    fn change<T: Clone>(record: &TestRecord, before: &T, after: &T) -> Change<T> {
+        Change {
+            description: record.description.clone(),
+            before: before.clone(),
+            after: after.clone(),
+        }
+    }
+
+    fn rank(rank: &Option<usize>) -> String {
+        match rank {
+            Some(rank) => format!("rank {rank}"),
+            None => String::from("not found"),
+        }
+    }
+
+    fn section<T>(
+        f: &mut Formatter<'_>,
+        heading: &str,
+        changes: &[Change<T>],
+        describe: impl Fn(&Change<T>) -> String,
+    ) -> std::fmt::Result {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        writeln!(f)?;
+        writeln!(f, "{heading}")?;
+        for change in changes {
+            writeln!(f, "  {}: {}", change.description, describe(change))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Regressions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Compared with baseline: {} newly failing, {} newly passing, {} rank improved, {} rank regressed, {} latency regressed",
+            self.newly_failing.len(),
+            self.newly_passing.len(),
+            self.rank_improved.len(),
+            self.rank_regressed.len(),
+            self.latency_regressed.len(),
+        )?;
+        Self::section(f, "Newly failing", &self.newly_failing, |change| {
+            format!("was {}, now {}", change.before, change.after)
+        })?;
+        Self::section(f, "Newly passing", &self.newly_passing, |change| {
+            format!("was {}, now {}", change.before, change.after)
+        })?;
+        Self::section(f, "Rank improved", &self.rank_improved, |change| {
+            format!(
+                "{} to {}",
+                Self::rank(&change.before),
+                Self::rank(&change.after)
+            )
+        })?;
+        Self::section(f, "Rank regressed", &self.rank_regressed, |change| {
+            format!(
+                "{} to {}",
+                Self::rank(&change.before),
+                Self::rank(&change.after)
+            )
+        })?;
+        Self::section(f, "Latency regressed", &self.latency_regressed, |change| {
+            format!("{}ms to {}ms", change.before, change.after)
+        })?;
+        if !self.failing_without_baseline.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Not passing and not in baseline")?;
+            for description in &self.failing_without_baseline {
+                writeln!(f, "  {description}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(
+        description: &str,
+        summary: Summary,
+        rank: Option<usize>,
+        elapsed_ms: u128,
+    ) -> TestRecord {
+        TestRecord {
+            description: String::from(description),
+            summary,
+            rank,
+            top: Vec::new(),
+            elapsed_ms,
+        }
+    }
+
+    #[test]
+    fn no_regressions_when_unchanged() {
+        let baseline = Baseline::new(vec![record("Find mn1", Summary::Passed, Some(1), 100)]);
+        let current = vec![record("Find mn1", Summary::Passed, Some(1), 100)];
+        assert_eq!(baseline.regressions(&current, 500), Regressions::default());
+    }
+
+    #[test]
+    fn passing_tests_missing_from_baseline_are_ignored() {
+        let baseline = Baseline::new(Vec::new());
+        let current = vec![record("Find mn1", Summary::Passed, Some(1), 100)];
+        assert_eq!(baseline.regressions(&current, 500), Regressions::default());
+    }
+
+    #[test]
+    fn failing_tests_missing_from_baseline_are_regressions() {
+        let baseline = Baseline::new(Vec::new());
+        let current = vec![record("Find mn1", Summary::Failed, None, 100)];
+        let regressions = baseline.regressions(&current, 500);
+
+        assert_eq!(
+            regressions.failing_without_baseline,
+            vec![String::from("Find mn1")]
+        );
+        assert!(!regressions.passed());
+        assert!(
+            regressions
+                .to_string()
+                .ends_with("\nNot passing and not in baseline\n  Find mn1\n")
+        );
+    }
+
+    #[test]
+    fn known_failures_pass() {
+        let baseline = Baseline::new(vec![
+            record("Find mn1", Summary::Failed, Some(4), 100),
+            record("Find mn2", Summary::Passed, Some(1), 100),
+        ]);
+        let current = vec![
+            record("Find mn1", Summary::Failed, Some(4), 100),
+            record("Find mn2", Summary::Passed, Some(1), 100),
+        ];
+        assert!(baseline.regressions(&current, 500).passed());
+    }
+
+    #[test]
+    fn rank_and_latency_regressions_fail() {
+        let baseline = Baseline::new(vec![record("Find mn1", Summary::Failed, Some(4), 100)]);
+        let slower = vec![record("Find mn1", Summary::Failed, Some(4), 700)];
+        let lower = vec![record("Find mn1", Summary::Failed, Some(5), 100)];
+        assert!(!baseline.regressions(&slower, 500).passed());
+        assert!(!baseline.regressions(&lower, 500).passed());
+    }
+
+    #[test]
+    fn newly_failing_and_rank_regressed() {
+        let baseline = Baseline::new(vec![record("Find mn1", Summary::Passed, Some(1), 100)]);
+        let current = vec![record("Find mn1", Summary::Failed, Some(4), 100)];
+
+        assert_eq!(
+            baseline.regressions(&current, 500),
+            Regressions {
+                newly_failing: vec![Change {
+                    description: String::from("Find mn1"),
+                    before: Summary::Passed,
+                    after: Summary::Failed,
+                }],
+                rank_regressed: vec![Change {
+                    description: String::from("Find mn1"),
+                    before: Some(1),
+                    after: Some(4),
+                }],
+                ..Regressions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn newly_passing_and_rank_improved() {
+        let baseline = Baseline::new(vec![record("Find mn1", Summary::Error, None, 100)]);
+        let current = vec![record("Find mn1", Summary::Passed, Some(2), 100)];
+
+        assert_eq!(
+            baseline.regressions(&current, 500),
+            Regressions {
+                newly_passing: vec![Change {
+                    description: String::from("Find mn1"),
+                    before: Summary::Error,
+                    after: Summary::Passed,
+                }],
+                rank_improved: vec![Change {
+                    description: String::from("Find mn1"),
+                    before: None,
+                    after: Some(2),
+                }],
+                ..Regressions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn latency_regressed_beyond_threshold() {
+        let baseline = Baseline::new(vec![
+            record("Slightly slower", Summary::Passed, None, 100),
+            record("Much slower", Summary::Passed, None, 100),
+        ]);
+        let current = vec![
+            record("Slightly slower", Summary::Passed, None, 600),
+            record("Much slower", Summary::Passed, None, 601),
+        ];
+
+        assert_eq!(
+            baseline.regressions(&current, 500),
+            Regressions {
+                latency_regressed: vec![Change {
+                    description: String::from("Much slower"),
+                    before: 100,
+                    after: 601,
+                }],
+                ..Regressions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn display_regressions() {
+        let baseline = Baseline::new(vec![
+            record("Find mn1", Summary::Passed, Some(1), 100),
+            record("Find mn2", Summary::Failed, None, 100),
+        ]);
+        let current = vec![
+            record("Find mn1", Summary::Failed, Some(3), 900),
+            record("Find mn2", Summary::Passed, Some(2), 100),
+        ];
+
+        assert_eq!(
+            baseline.regressions(&current, 500).to_string(),
+            "Compared with baseline: 1 newly failing, 1 newly passing, 1 rank improved, 1 rank regressed, 1 latency regressed\n\
+            \n\
+            Newly failing\n  Find mn1: was PASSED, now FAILED\n\
+            \n\
+            Newly passing\n  Find mn2: was FAILED, now PASSED\n\
+            \n\
+            Rank improved\n  Find mn2: not found to rank 2\n\
+            \n\
+            Rank regressed\n  Find mn1: rank 1 to rank 3\n\
+            \n\
+            Latency regressed\n  Find mn1: 100ms to 900ms\n"
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let directory = TempDir::new().unwrap();
+        let path = directory.path().join("baseline.json");
+        let baseline = Baseline::new(vec![record("Find mn1", Summary::Passed, Some(1), 100)]);
+
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+
+        assert_eq!(loaded, baseline);
+    }
+
+    #[test]
+    fn missing_baseline_is_error() {
+        let error = Baseline::load(Path::new("no-such-baseline.json")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Error reading baseline no-such-baseline.json"
+        );
+    }
+}
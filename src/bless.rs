@@ -0,0 +1,412 @@
+use crate::expected::Expected;
+use crate::outcome::Outcome;
+use crate::rank::Rank;
+use crate::test_case::Origin;
+use crate::test_result::TestResult;
+use anyhow::{Context, Result};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use toml_edit::{DocumentMut, Item, Table, value};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edit {
+    pub description: String,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Unblessable {
+    NotFound,
+    Error,
+    NotInFile,
+    Matrix,
+    TestTable,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Skipped {
+    pub description: String,
+    pub reason: Unblessable,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Blessing {
+    pub edits: Vec<Edit>,
+    pub skipped: Vec<Skipped>,
+}
+
+pub fn is_selected(description: &str, selections: &[String]) -> bool {
+    selections
+        .iter()
+        .any(|selection| description.contains(selection.as_str()))
+}
+
+pub fn bless_file(path: &Path, results: &[TestResult]) -> Result<Blessing> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("Error reading file {}", path.display()))?;
+    let (blessed, blessing) = bless(source.as_str(), results)?;
+    if !blessing.edits.is_empty() {
+        std::fs::write(path, blessed)
+            .with_context(|| format!("Error writing file {}", path.display()))?;
+    }
+    Ok(blessing)
+}
+
+pub fn bless(source: &str, results: &[TestResult]) -> Result<(String, Blessing)> {
+    let mut document = source
+        .parse::<DocumentMut>()
+        .context("Failed to parse TOML.")?;
+    let mut blessing = Blessing::default();
+
+    for result in results {
+        let description = result.test_case.description.clone();
+        match observed_rank(result) {
+            Ok(Some(_)) if result.test_case.origin == Origin::Matrix => {
+                blessing.skip(description, Unblessable::Matrix)
+            }
+            Ok(Some(_)) if result.test_case.origin == Origin::TestTable => {
+                blessing.skip(description, Unblessable::TestTable)
+            }
+            Ok(Some((from, to))) => match find_test_case(&mut document, description.as_str()) {
+                Some(test_case) => {
+                    set_min_rank(test_case, to);
+                    blessing.edits.push(Edit {
+                        description,
+                        from,
+                        to,
+                    });
+                }
+                None => blessing.skip(description, Unblessable::NotInFile),
+            },
+            Ok(None) => {}
+            Err(reason) => blessing.skip(description, reason),
+        }
+    }
+
+    Ok((document.to_string(), blessing))
+}
+
+fn observed_rank(result: &TestResult) -> Result<Option<(usize, usize)>, Unblessable> {
+    match &result.outcome {
+        Outcome::Ranked {
+            results: _,
+            rank: Rank::TooLow { minimum, actual } | Rank::Sufficient { minimum, actual },
+        } if minimum != actual => Ok(Some((*minimum, *actual))),
+        Outcome::Ranked {
+            results: _,
+            rank: Rank::NotFound { .. },
+        } => Err(Unblessable::NotFound),
+        Outcome::Error { .. }
+            if matches!(result.test_case.expected, Some(Expected::Ranked { .. })) =>
+        {
+            Err(Unblessable::Error)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn find_test_case<'a>(document: &'a mut DocumentMut, description: &str) -> Option<&'a mut Table> {
+    let mut test_cases: Vec<&mut Table> = Vec::new();
+    for (key, item) in document.iter_mut() {
+        match key.get() {
+            "test-case" => test_cases.extend(tables(item)),
+            "group" => {
+                for group in tables(item) {
+                    if let Some(item) = group.get_mut("test-case") {
+                        test_cases.extend(tables(item));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    test_cases.into_iter().find(|table| {
+        table
+            .get("description")
+            .and_then(|item| item.as_str())
+            .is_some_and(|found| found == description)
+    })
+}
+
+fn tables(item: &mut Item) -> impl Iterator<Item = &mut Table> {
+    item.as_array_of_tables_mut()
+        .into_iter()
+        .flat_map(|tables| tables.iter_mut())
+}
+
+fn set_min_rank(test_case: &mut Table, rank: usize) {
+    let item: &mut Item = &mut test_case["expected"]["min-rank"];
+    let decor = item.as_value().map(|existing| existing.decor().clone());
+    *item = value(rank as i64);
+    if let (Some(decor), Some(new)) = (decor, item.as_value_mut()) {
+        *new.decor_mut() = decor;
+    }
+}
+
+impl Blessing {
+    fn skip(&mut self, description: String, reason: Unblessable) {
+        self.skipped.push(Skipped {
+            description,
+            reason,
+        });
+    }
+}
+
+impl Display for Blessing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.edits.is_empty() && self.skipped.is_empty() {
+            return writeln!(f, "No expectations needed blessing");
+        }
+        for edit in &self.edits {
+            writeln!(f, "{edit}")?;
+        }
+        for skipped in &self.skipped {
+            writeln!(f, "{skipped}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Skipped {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self.reason {
+            Unblessable::NotFound => "expected result was not found",
+            Unblessable::Error => "the search encountered an error",
+            Unblessable::NotInFile => "no test case with this description in the suite file",
+            Unblessable::Matrix => "expanded from a matrix, edit `min-rank` on its test case",
+            Unblessable::TestTable => "test-table row, edit `min-rank` in its table",
+        };
+        write!(f, "Cannot bless `{}`: {reason}", self.description)
+    }
+}
+
+impl Display for Edit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Blessed `{}`: min-rank {} -> {}",
+            self.description, self.from, self.to
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::search_results::SearchResults;
+    use crate::test_fixtures::test_case;
+    use std::time::Duration;
+
+    const SOURCE: &str = r#"[settings]
+endpoint = "http://localhost/api/search/instant"
+
+# Ranked tests
+[[test-case]]
+description = "Too low"
+query = "mn2"
+expected.suttaplex = "mn2"
+expected.min-rank = 1 # Should be first
+
+[[test-case]]
+description = "Inline expectation"
+query = "mn3"
+expected = { suttaplex = "mn3", min-rank = 1 }
+"#;
+
+    fn ranked(description: &str, rank: Rank) -> TestResult {
+        TestResult {
+            test_case: test_case(description),
+            elapsed: Duration::from_millis(10),
+            outcome: Outcome::Ranked {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn2"),
+                    results: Vec::new(),
+                },
+                rank,
+            },
+            body: None,
+        }
+    }
+
+    #[test]
+    fn selection_matches_substring() {
+        let selections = vec![String::from("low")];
+        assert!(is_selected("Too low", &selections));
+        assert!(!is_selected("Inline expectation", &selections));
+        assert!(!is_selected("Too low", &[]));
+    }
+
+    #[test]
+    fn rewrites_min_rank_preserving_comments() {
+        let results = vec![ranked(
+            "Too low",
+            Rank::TooLow {
+                minimum: 1,
+                actual: 4,
+            },
+        )];
+
+        let (blessed, blessing) = bless(SOURCE, &results).unwrap();
+
+        assert_eq!(
+            blessed,
+            SOURCE.replace(
+                "expected.min-rank = 1 # Should be first",
+                "expected.min-rank = 4 # Should be first"
+            )
+        );
+        assert_eq!(
+            blessing.edits,
+            vec![Edit {
+                description: String::from("Too low"),
+                from: 1,
+                to: 4
+            }]
+        );
+        assert!(blessing.skipped.is_empty());
+    }
+
+    #[test]
+    fn rewrites_inline_expectation() {
+        let results = vec![ranked(
+            "Inline expectation",
+            Rank::Sufficient {
+                minimum: 3,
+                actual: 2,
+            },
+        )];
+
+        let (blessed, _) = bless(SOURCE, &results).unwrap();
+
+        assert_eq!(
+            blessed,
+            SOURCE.replace(
+                "expected = { suttaplex = \"mn3\", min-rank = 1 }",
+                "expected = { suttaplex = \"mn3\", min-rank = 2 }"
+            )
+        );
+    }
+
+    #[test]
+    fn leaves_matching_ranks_alone() {
+        let results = vec![ranked(
+            "Too low",
+            Rank::Sufficient {
+                minimum: 1,
+                actual: 1,
+            },
+        )];
+
+        let (blessed, blessing) = bless(SOURCE, &results).unwrap();
+
+        assert_eq!(blessed, SOURCE);
+        assert_eq!(blessing, Blessing::default());
+        assert_eq!(blessing.to_string(), "No expectations needed blessing\n");
+    }
+
+    #[test]
+    fn reports_selected_tests_that_cannot_be_blessed() {
+        let results = vec![
+            ranked("Inline expectation", Rank::NotFound { minimum: 1 }),
+            ranked(
+                "Not in this file",
+                Rank::TooLow {
+                    minimum: 1,
+                    actual: 2,
+                },
+            ),
+        ];
+
+        let (blessed, blessing) = bless(SOURCE, &results).unwrap();
+
+        assert_eq!(blessed, SOURCE);
+        assert!(blessing.edits.is_empty());
+        assert_eq!(
+            blessing.to_string(),
+            "Cannot bless `Inline expectation`: expected result was not found\n\
+            Cannot bless `Not in this file`: no test case with this description in the suite file\n"
+        );
+    }
+
+    #[test]
+    fn reports_matrix_cases_instead_of_blessing() {
+        let source = SOURCE.replace(
+            "description = \"Too low\"",
+            "description = \"Too low [site-language=de]\"",
+        );
+        let mut result = ranked(
+            "Too low [site-language=de]",
+            Rank::TooLow {
+                minimum: 1,
+                actual: 4,
+            },
+        );
+        result.test_case.origin = Origin::Matrix;
+
+        let (blessed, blessing) = bless(source.as_str(), &[result]).unwrap();
+
+        assert_eq!(blessed, source);
+        assert_eq!(
+            blessing.to_string(),
+            "Cannot bless `Too low [site-language=de]`: expanded from a matrix, edit `min-rank` on its test case\n"
+        );
+    }
+
+    #[test]
+    fn reports_test_table_rows_instead_of_blessing() {
+        let mut result = ranked(
+            "rows.csv row 2: mn2",
+            Rank::Sufficient {
+                minimum: 3,
+                actual: 2,
+            },
+        );
+        result.test_case.origin = Origin::TestTable;
+
+        let (blessed, blessing) = bless(SOURCE, &[result]).unwrap();
+
+        assert_eq!(blessed, SOURCE);
+        assert_eq!(
+            blessing.to_string(),
+            "Cannot bless `rows.csv row 2: mn2`: test-table row, edit `min-rank` in its table\n"
+        );
+    }
+
+    #[test]
+    fn display_edit() {
+        let edit = Edit {
+            description: String::from("Too low"),
+            from: 1,
+            to: 4,
+        };
+        assert_eq!(edit.to_string(), "Blessed `Too low`: min-rank 1 -> 4");
+    }
+
+    #[test]
+    fn rewrites_min_rank_in_group() {
+        let source = r#"[settings]
+endpoint = "http://localhost/api/search/instant"
+
+[[group]]
+name = "German"
+
+[[group.test-case]]
+description = "Too low"
+query = "mn2"
+expected = { suttaplex = "mn2", min-rank = 1 }
+"#;
+        let results = vec![ranked(
+            "Too low",
+            Rank::TooLow {
+                minimum: 1,
+                actual: 2,
+            },
+        )];
+
+        let (blessed, _) = bless(source, &results).unwrap();
+
+        assert_eq!(blessed, source.replace("min-rank = 1", "min-rank = 2"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::identifiers::{DictionaryUrl, SuttaplexUid, TextUrl, VolpageReference};
+use crate::response::dictionary::dictionary_results;
+use crate::response::suttaplex::suttaplex_results;
+use crate::response::texts::text_results;
+use crate::response::volpage::volpage_results;
+use crate::search_results::SearchResults;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CategoryResults {
+    pub text: Vec<TextUrl>,
+    pub dictionary: Vec<DictionaryUrl>,
+    pub suttaplex: Vec<SuttaplexUid>,
+    pub volpage: Vec<VolpageReference>,
+}
+
+impl CategoryResults {
+    pub fn new(json: &str) -> Self {
+        Self {
+            text: text_results(json).unwrap_or_default(),
+            dictionary: dictionary_results(json).unwrap_or_default(),
+            suttaplex: suttaplex_results(json).unwrap_or_default(),
+            volpage: volpage_results(json).unwrap_or_default(),
+        }
+    }
+
+    pub fn categories(&self) -> Vec<(&'static str, Vec<String>)> {
+        vec![
+            ("text", SearchResults::ids_of(&self.text)),
+            ("dictionary", SearchResults::ids_of(&self.dictionary)),
+            ("suttaplex", SearchResults::ids_of(&self.suttaplex)),
+            ("volpage", SearchResults::ids_of(&self.volpage)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_json::{NO_RESULTS_JSON, SUTTAPLEX_MN_FIRST_THREE_JSON};
+
+    #[test]
+    fn bad_json_gives_no_results() {
+        assert_eq!(
+            CategoryResults::new("This is not JSON"),
+            CategoryResults::default()
+        );
+    }
+
+    #[test]
+    fn no_results() {
+        assert_eq!(
+            CategoryResults::new(NO_RESULTS_JSON),
+            CategoryResults::default()
+        );
+    }
+
+    #[test]
+    fn suttaplex_results_only() {
+        assert_eq!(
+            CategoryResults::new(SUTTAPLEX_MN_FIRST_THREE_JSON),
+            CategoryResults {
+                suttaplex: vec![
+                    SuttaplexUid::from("mn1"),
+                    SuttaplexUid::from("mn2"),
+                    SuttaplexUid::from("mn3")
+                ],
+                ..CategoryResults::default()
+            }
+        );
+    }
+
+    #[test]
+    fn text_and_dictionary_results() {
+        let json = r#"
+        {
+            "hits": [
+                {
+                    "uid": "mn1",
+                    "lang": "en",
+                    "author_uid": "sujato",
+                    "url": "/mn1/en/sujato"
+                },
+                {
+                    "url": "/define/metta",
+                    "category": "dictionary"
+                }
+            ],
+            "fuzzy_dictionary": [],
+            "suttaplex": []
+        }
+        "#;
+
+        assert_eq!(
+            CategoryResults::new(json),
+            CategoryResults {
+                text: vec![TextUrl::from("/mn1/en/sujato")],
+                dictionary: vec![DictionaryUrl::from("/define/metta")],
+                ..CategoryResults::default()
+            }
+        );
+    }
+
+    #[test]
+    fn categories_are_listed_as_ids() {
+        let results = CategoryResults {
+            suttaplex: vec![SuttaplexUid::from("mn1")],
+            ..CategoryResults::default()
+        };
+
+        assert_eq!(
+            results.categories(),
+            vec![
+                ("text", vec![]),
+                ("dictionary", vec![]),
+                ("suttaplex", vec![String::from("mn1")]),
+                ("volpage", vec![]),
+            ]
+        );
+    }
+}
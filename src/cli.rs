@@ -0,0 +1,509 @@
+use crate::overrides::Override;
+use crate::reporter::Verbosity;
+use crate::selection::Selection;
+use anyhow::{Context, Result};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use regex::Regex;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser, PartialEq)]
+#[command(
+    version,
+    about = "Acceptance testing for SuttaCentral search",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+pub struct Arguments {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[arg(
+        value_name = "SUITE",
+        required = true,
+        help = "The test suite TOML files to run, as paths, glob patterns or directories"
+    )]
+    pub suites: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Save the results of this run as a baseline"
+    )]
+    pub save_baseline: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Compare the results of this run with a baseline"
+    )]
+    pub baseline: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "MILLISECONDS",
+        default_value_t = 500,
+        help = "How much slower than the baseline a test must be to count as a latency regression"
+    )]
+    pub latency_threshold: u128,
+
+    #[arg(long, help = "Record this run in the history file next to the suite")]
+    pub history: bool,
+
+    #[arg(
+        long,
+        value_name = "DESCRIPTION",
+        help = "Rewrite min-rank in the suite to match the observed rank for tests whose description contains this"
+    )]
+    pub bless: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Write a JUnit XML report of the run"
+    )]
+    pub junit: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Write a self-contained HTML report of the run"
+    )]
+    pub html: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Format::Text,
+        help = "How results are printed as each test completes"
+    )]
+    pub format: Format,
+
+    #[arg(
+        long,
+        value_name = "COUNT",
+        default_value_t = 5,
+        help = "How many of the actual results to list under each failed test"
+    )]
+    pub show_top: usize,
+
+    #[arg(
+        short,
+        long,
+        action = ArgAction::Count,
+        help = "Print each request, and with -vv the response body of failed tests"
+    )]
+    pub verbose: u8,
+
+    #[arg(
+        short,
+        long,
+        conflicts_with = "verbose",
+        help = "Only print failed tests and the summary"
+    )]
+    pub quiet: bool,
+
+    #[arg(
+        long,
+        value_name = "TAG",
+        help = "Only run tests with this tag. Can be given more than once"
+    )]
+    pub tag: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "TAG",
+        help = "Skip tests with this tag. Can be given more than once"
+    )]
+    pub exclude_tag: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "TEXT",
+        help = "Only run tests whose description contains this. Can be given more than once"
+    )]
+    pub only: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "REGEX",
+        help = "Only run tests whose description matches this regular expression"
+    )]
+    pub only_regex: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Run against this environment from the suite settings instead of the default"
+    )]
+    pub env: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "KEY=VALUE",
+        help = "Override a value in the suite, such as settings.endpoint=URL or defaults.limit=50. Can be given more than once"
+    )]
+    pub set: Vec<Override>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Tap,
+    Markdown,
+}
+
+impl Arguments {
+    pub fn selection(&self) -> Result<Selection> {
+        let pattern = match &self.only_regex {
+            Some(pattern) => Some(
+                Regex::new(pattern)
+                    .with_context(|| format!("Invalid --only-regex pattern `{pattern}`"))?,
+            ),
+            None => None,
+        };
+        Ok(Selection {
+            tags: self.tag.clone(),
+            excluded_tags: self.exclude_tag.clone(),
+            descriptions: self.only.clone(),
+            pattern,
+        })
+    }
+
+    pub fn verbosity(&self) -> Verbosity {
+        match (self.quiet, self.verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::VeryVerbose,
+        }
+    }
+
+    pub fn has_single_endpoint_options(&self) -> bool {
+        self.baseline.is_some()
+            || self.save_baseline.is_some()
+            || self.history
+            || !self.bless.is_empty()
+            || self.junit.is_some()
+            || self.html.is_some()
+    }
+
+    pub fn has_single_suite_options(&self) -> bool {
+        self.baseline.is_some()
+            || self.save_baseline.is_some()
+            || self.junit.is_some()
+            || self.html.is_some()
+            || self.format == Format::Tap
+    }
+}
+
+#[derive(Debug, PartialEq, Subcommand)]
+pub enum Command {
+    #[command(about = "Show the recorded history of each test in a suite")]
+    History {
+        #[arg(value_name = "SUITE", help = "The test suite TOML file")]
+        suite: PathBuf,
+    },
+    #[command(about = "Check test suites for problems without sending any requests")]
+    Lint {
+        #[arg(
+            value_name = "SUITE",
+            required = true,
+            help = "The test suite TOML files to check, as paths, glob patterns or directories"
+        )]
+        suites: Vec<PathBuf>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_arguments_provided() {
+        let error = Arguments::try_parse_from(["search-test"]).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+    }
+
+    #[test]
+    fn file_name_provided() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "test_cases/example.toml"]).unwrap();
+        assert_eq!(
+            arguments.suites,
+            vec![PathBuf::from("test_cases/example.toml")]
+        );
+        assert_eq!(arguments.command, None);
+    }
+
+    #[test]
+    fn several_suites_provided() {
+        let arguments = Arguments::try_parse_from([
+            "search-test",
+            "suite_examples/volpage.toml",
+            "suite_examples/title.toml",
+            "suite_examples/*.toml",
+        ])
+        .unwrap();
+        assert_eq!(
+            arguments.suites,
+            vec![
+                PathBuf::from("suite_examples/volpage.toml"),
+                PathBuf::from("suite_examples/title.toml"),
+                PathBuf::from("suite_examples/*.toml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_suite_options() {
+        let parse = |args: &[&str]| {
+            let mut all = vec!["search-test", "example.toml"];
+            all.extend_from_slice(args);
+            Arguments::try_parse_from(all).unwrap()
+        };
+        assert!(!parse(&[]).has_single_suite_options());
+        assert!(!parse(&["--history", "--format", "json"]).has_single_suite_options());
+        assert!(parse(&["--junit", "report.xml"]).has_single_suite_options());
+        assert!(parse(&["--format", "tap"]).has_single_suite_options());
+    }
+
+    #[test]
+    fn baseline_options() {
+        let arguments = Arguments::try_parse_from([
+            "search-test",
+            "example.toml",
+            "--baseline",
+            "previous.json",
+            "--save-baseline",
+            "latest.json",
+            "--latency-threshold",
+            "250",
+        ])
+        .unwrap();
+
+        assert_eq!(arguments.baseline, Some(PathBuf::from("previous.json")));
+        assert_eq!(arguments.save_baseline, Some(PathBuf::from("latest.json")));
+        assert_eq!(arguments.latency_threshold, 250);
+    }
+
+    #[test]
+    fn latency_threshold_has_default() {
+        let arguments = Arguments::try_parse_from(["search-test", "example.toml"]).unwrap();
+        assert_eq!(arguments.latency_threshold, 500);
+    }
+
+    #[test]
+    fn history_is_opt_in() {
+        let arguments = Arguments::try_parse_from(["search-test", "example.toml"]).unwrap();
+        assert!(!arguments.history);
+
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--history"]).unwrap();
+        assert!(arguments.history);
+    }
+
+    #[test]
+    fn bless_can_be_repeated() {
+        let arguments = Arguments::try_parse_from([
+            "search-test",
+            "example.toml",
+            "--bless",
+            "metta",
+            "--bless",
+            "Too low",
+        ])
+        .unwrap();
+        assert_eq!(arguments.bless, vec!["metta", "Too low"]);
+    }
+
+    #[test]
+    fn junit_report_path() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--junit", "report.xml"])
+                .unwrap();
+        assert_eq!(arguments.junit, Some(PathBuf::from("report.xml")));
+    }
+
+    #[test]
+    fn html_report_path() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--html", "report.html"])
+                .unwrap();
+        assert_eq!(arguments.html, Some(PathBuf::from("report.html")));
+    }
+
+    #[test]
+    fn format_defaults_to_text() {
+        let arguments = Arguments::try_parse_from(["search-test", "example.toml"]).unwrap();
+        assert_eq!(arguments.format, Format::Text);
+    }
+
+    #[test]
+    fn json_format() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--format", "json"]).unwrap();
+        assert_eq!(arguments.format, Format::Json);
+    }
+
+    #[test]
+    fn tap_format() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--format", "tap"]).unwrap();
+        assert_eq!(arguments.format, Format::Tap);
+    }
+
+    #[test]
+    fn show_top() {
+        let arguments = Arguments::try_parse_from(["search-test", "example.toml"]).unwrap();
+        assert_eq!(arguments.show_top, 5);
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--show-top", "0"]).unwrap();
+        assert_eq!(arguments.show_top, 0);
+    }
+
+    #[test]
+    fn verbosity() {
+        let verbosity = |args: &[&str]| {
+            let mut all = vec!["search-test", "example.toml"];
+            all.extend_from_slice(args);
+            Arguments::try_parse_from(all).unwrap().verbosity()
+        };
+        assert_eq!(verbosity(&[]), Verbosity::Normal);
+        assert_eq!(verbosity(&["-q"]), Verbosity::Quiet);
+        assert_eq!(verbosity(&["-v"]), Verbosity::Verbose);
+        assert_eq!(verbosity(&["-vv"]), Verbosity::VeryVerbose);
+        assert_eq!(verbosity(&["--verbose", "-vvv"]), Verbosity::VeryVerbose);
+    }
+
+    #[test]
+    fn quiet_conflicts_with_verbose() {
+        let error =
+            Arguments::try_parse_from(["search-test", "example.toml", "-q", "-v"]).unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn tags() {
+        let arguments = Arguments::try_parse_from([
+            "search-test",
+            "example.toml",
+            "--tag",
+            "smoke",
+            "--tag",
+            "pali",
+            "--exclude-tag",
+            "slow",
+        ])
+        .unwrap();
+        assert_eq!(arguments.tag, vec!["smoke", "pali"]);
+        assert_eq!(arguments.exclude_tag, vec!["slow"]);
+    }
+
+    #[test]
+    fn only_descriptions() {
+        let arguments = Arguments::try_parse_from([
+            "search-test",
+            "example.toml",
+            "--only",
+            "metta",
+            "--only-regex",
+            "^Metta",
+        ])
+        .unwrap();
+        let selection = arguments.selection().unwrap();
+        assert_eq!(selection.descriptions, vec!["metta"]);
+        assert_eq!(selection.pattern.unwrap().as_str(), "^Metta");
+    }
+
+    #[test]
+    fn invalid_regex_is_error() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--only-regex", "(metta"])
+                .unwrap();
+        let error = arguments.selection().unwrap_err();
+        assert_eq!(error.to_string(), "Invalid --only-regex pattern `(metta`");
+    }
+
+    #[test]
+    fn markdown_format() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--format", "markdown"])
+                .unwrap();
+        assert_eq!(arguments.format, Format::Markdown);
+    }
+
+    #[test]
+    fn single_endpoint_options() {
+        let arguments = Arguments::try_parse_from(["search-test", "example.toml"]).unwrap();
+        assert!(!arguments.has_single_endpoint_options());
+
+        let arguments =
+            Arguments::try_parse_from(["search-test", "example.toml", "--history"]).unwrap();
+        assert!(arguments.has_single_endpoint_options());
+    }
+
+    #[test]
+    fn history_subcommand() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "history", "example.toml"]).unwrap();
+        assert_eq!(
+            arguments.command,
+            Some(Command::History {
+                suite: PathBuf::from("example.toml")
+            })
+        );
+        assert!(arguments.suites.is_empty());
+    }
+
+    #[test]
+    fn environment() {
+        let arguments = Arguments::try_parse_from(["search-test", "suite.toml"]).unwrap();
+        assert_eq!(arguments.env, None);
+        let arguments =
+            Arguments::try_parse_from(["search-test", "--env", "staging", "suite.toml"]).unwrap();
+        assert_eq!(arguments.env, Some(String::from("staging")));
+    }
+
+    #[test]
+    fn overrides() {
+        let arguments = Arguments::try_parse_from([
+            "search-test",
+            "--set",
+            "settings.endpoint=http://localhost:8080/api/search/instant",
+            "--set",
+            "defaults.limit=50",
+            "suite.toml",
+        ])
+        .unwrap();
+        assert_eq!(
+            arguments.set,
+            vec![
+                "settings.endpoint=http://localhost:8080/api/search/instant"
+                    .parse::<Override>()
+                    .unwrap(),
+                "defaults.limit=50".parse::<Override>().unwrap(),
+            ]
+        );
+        assert!(
+            Arguments::try_parse_from(["search-test", "--set", "limit", "suite.toml"]).is_err()
+        );
+    }
+
+    #[test]
+    fn lint_subcommand() {
+        let arguments =
+            Arguments::try_parse_from(["search-test", "lint", "first.toml", "second.toml"])
+                .unwrap();
+        assert_eq!(
+            arguments.command,
+            Some(Command::Lint {
+                suites: vec![PathBuf::from("first.toml"), PathBuf::from("second.toml")]
+            })
+        );
+        assert!(Arguments::try_parse_from(["search-test", "lint"]).is_err());
+    }
+}
@@ -0,0 +1,451 @@
+use crate::category_results::CategoryResults;
+use crate::summary::Summary;
+use crate::test_case::TestCase;
+use crate::test_result::TestResult;
+use crate::timed_response::TimedResponse;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub description: String,
+    pub primary: TestResult,
+    pub secondary: TestResult,
+    primary_results: CategoryResults,
+    secondary_results: CategoryResults,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankChange {
+    pub from: Option<usize>,
+    pub to: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryDiff {
+    pub category: &'static str,
+    pub gained: Vec<String>,
+    pub lost: Vec<String>,
+    pub overlap: f64,
+}
+
+impl Comparison {
+    pub fn new(test_case: &TestCase, primary: TimedResponse, secondary: TimedResponse) -> Self {
+        let primary_results = Self::category_results(&primary);
+        let secondary_results = Self::category_results(&secondary);
+        Self {
+            description: test_case.description.clone(),
+            primary: TestResult::new(test_case, primary),
+            secondary: TestResult::new(test_case, secondary),
+            primary_results,
+            secondary_results,
+        }
+    }
+
+    fn category_results(response: &TimedResponse) -> CategoryResults {
+        response
+            .json
+            .as_ref()
+            .map(|json| CategoryResults::new(json))
+            .unwrap_or_default()
+    }
+
+    pub fn outcome_changed(&self) -> bool {
+        Summary::from(&self.primary.outcome) != Summary::from(&self.secondary.outcome)
+    }
+
+    pub fn differs(&self) -> bool {
+        self.outcome_changed()
+            || self.rank_change().is_some_and(|change| change.moved())
+            || self.category_diffs().iter().any(|diff| diff.changed())
+    }
+
+    pub fn rank_change(&self) -> Option<RankChange> {
+        let from = self.primary.outcome.results()?.rank();
+        let to = self.secondary.outcome.results()?.rank();
+        Some(RankChange { from, to })
+    }
+
+    pub fn category_diffs(&self) -> Vec<CategoryDiff> {
+        self.primary_results
+            .categories()
+            .into_iter()
+            .zip(self.secondary_results.categories())
+            .filter_map(|((category, primary), (_, secondary))| {
+                CategoryDiff::new(category, &primary, &secondary)
+            })
+            .collect()
+    }
+
+    fn side(result: &TestResult) -> String {
+        let summary = Summary::from(&result.outcome).to_string();
+        let elapsed = format!("{}ms", result.elapsed.as_millis());
+        format!("{summary:7} {elapsed:6}")
+    }
+
+    fn rank_line(&self) -> Option<String> {
+        let change = self.rank_change()?;
+        if !change.moved() {
+            return None;
+        }
+        let results = self.primary.outcome.results()?;
+        Some(format!(
+            "Rank of {} moved from {} to {}",
+            TestResult::search_term(results),
+            RankChange::describe(change.from),
+            RankChange::describe(change.to)
+        ))
+    }
+}
+
+impl RankChange {
+    pub fn moved(&self) -> bool {
+        self.from != self.to
+    }
+
+    pub fn improved(&self) -> bool {
+        match (self.from, self.to) {
+            (None, Some(_)) => true,
+            (Some(from), Some(to)) => to < from,
+            _ => false,
+        }
+    }
+
+    pub fn regressed(&self) -> bool {
+        match (self.from, self.to) {
+            (Some(_), None) => true,
+            (Some(from), Some(to)) => to > from,
+            _ => false,
+        }
+    }
+
+    fn describe(rank: Option<usize>) -> String {
+        match rank {
+            Some(rank) => rank.to_string(),
+            None => String::from("not found"),
+        }
+    }
+}
+
+impl CategoryDiff {
+    fn new(category: &'static str, primary: &[String], secondary: &[String]) -> Option<Self> {
+        let primary = Self::unique(primary);
+        let secondary = Self::unique(secondary);
+
+        if primary.is_empty() && secondary.is_empty() {
+            return None;
+        }
+
+        let gained: Vec<String> = secondary
+            .iter()
+            .filter(|id| !primary.contains(id))
+            .cloned()
+            .collect();
+        let lost: Vec<String> = primary
+            .iter()
+            .filter(|id| !secondary.contains(id))
+            .cloned()
+            .collect();
+        let common = primary.len() - lost.len();
+        let union = primary.len() + gained.len();
+
+        Some(Self {
+            category,
+            gained,
+            lost,
+            overlap: common as f64 / union as f64,
+        })
+    }
+
+    fn unique(ids: &[String]) -> Vec<String> {
+        let mut unique: Vec<String> = Vec::new();
+        for id in ids {
+            if !unique.contains(id) {
+                unique.push(id.clone());
+            }
+        }
+        unique
+    }
+
+    pub fn changed(&self) -> bool {
+        !self.gained.is_empty() || !self.lost.is_empty()
+    }
+}
+
+impl Display for CategoryDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:.0}% overlap", self.category, self.overlap * 100.0)?;
+        if !self.gained.is_empty() {
+            write!(f, ", gained {}", self.gained.join(" "))?;
+        }
+        if !self.lost.is_empty() {
+            write!(f, ", lost {}", self.lost.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let changed = match self.outcome_changed() {
+            true => "CHANGED",
+            false => "SAME",
+        };
+        writeln!(
+            f,
+            "{changed:7} {} {} {}",
+            Self::side(&self.primary),
+            Self::side(&self.secondary),
+            self.description
+        )?;
+        if let Some(rank_line) = self.rank_line() {
+            writeln!(f, "  {rank_line}")?;
+        }
+        for diff in self.category_diffs().iter().filter(|diff| diff.changed()) {
+            writeln!(f, "  {diff}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected::Expected;
+    use crate::identifiers::{SearchResultKey, SuttaplexUid};
+    use crate::test_case::Origin;
+    use crate::test_json::{NO_RESULTS_JSON, SUTTAPLEX_MN_FIRST_THREE_JSON, SUTTAPLEX_MN1_JSON};
+    use anyhow::anyhow;
+    use std::io::Write;
+    use std::time::Duration;
+
+    const SUTTAPLEX_MN3_MN1_JSON: &str = r#"
+    {
+        "suttaplex" : [
+            { "uid": "mn3" },
+            { "uid": "mn1" },
+            { "uid": "mn4" }
+        ]
+    }
+    "#;
+
+    fn test_case(expected: Option<Expected>) -> TestCase {
+        TestCase {
+            description: String::from("Find suttaplex mn1"),
+            query: String::from("mn1"),
+            limit: 50,
+            site_language: String::from("en"),
+            restrict: String::from("all"),
+            selected_languages: vec![String::from("en")],
+            match_partial: false,
+            expected,
+            tags: Vec::new(),
+            group: None,
+            origin: Origin::Suite,
+        }
+    }
+
+    fn expect_mn1() -> Option<Expected> {
+        Some(Expected::Unranked {
+            key: SearchResultKey::Suttaplex {
+                uid: SuttaplexUid::from("mn1"),
+            },
+        })
+    }
+
+    fn response(millis: u64, json: &str) -> TimedResponse {
+        TimedResponse {
+            elapsed: Duration::from_millis(millis),
+            json: Ok(String::from(json)),
+            body: None,
+        }
+    }
+
+    fn message(lines: &[&str]) -> String {
+        let mut expected = Vec::new();
+        for line in lines {
+            writeln!(&mut expected, "{line}").unwrap();
+        }
+        String::from_utf8(expected).unwrap()
+    }
+
+    #[test]
+    fn same_outcome() {
+        let comparison = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN1_JSON),
+            response(20, SUTTAPLEX_MN_FIRST_THREE_JSON),
+        );
+        assert!(!comparison.outcome_changed());
+    }
+
+    #[test]
+    fn changed_outcome() {
+        let comparison = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN1_JSON),
+            response(20, NO_RESULTS_JSON),
+        );
+        assert!(comparison.outcome_changed());
+    }
+
+    #[test]
+    fn changed_outcome_on_error() {
+        let comparison = Comparison::new(
+            &test_case(None),
+            response(10, SUTTAPLEX_MN1_JSON),
+            TimedResponse {
+                elapsed: Duration::from_millis(20),
+                json: Err(anyhow!("Error sending HTTP request")),
+                body: None,
+            },
+        );
+        assert!(comparison.outcome_changed());
+    }
+
+    #[test]
+    fn differs_when_results_change_with_same_outcome() {
+        let same = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN1_JSON),
+            response(20, SUTTAPLEX_MN1_JSON),
+        );
+        let moved = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN_FIRST_THREE_JSON),
+            response(20, SUTTAPLEX_MN3_MN1_JSON),
+        );
+        assert!(!same.differs());
+        assert!(!moved.outcome_changed());
+        assert!(moved.differs());
+    }
+
+    #[test]
+    fn no_rank_change_without_expected() {
+        let comparison = Comparison::new(
+            &test_case(None),
+            response(10, SUTTAPLEX_MN1_JSON),
+            response(20, SUTTAPLEX_MN3_MN1_JSON),
+        );
+        assert_eq!(comparison.rank_change(), None);
+    }
+
+    #[test]
+    fn rank_regressed() {
+        let comparison = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN_FIRST_THREE_JSON),
+            response(20, SUTTAPLEX_MN3_MN1_JSON),
+        );
+        let change = comparison.rank_change().unwrap();
+        assert_eq!(
+            change,
+            RankChange {
+                from: Some(1),
+                to: Some(2)
+            }
+        );
+        assert!(change.moved());
+        assert!(change.regressed());
+        assert!(!change.improved());
+    }
+
+    #[test]
+    fn rank_improved_from_not_found() {
+        let change = RankChange {
+            from: None,
+            to: Some(4),
+        };
+        assert!(change.improved());
+        assert!(!change.regressed());
+    }
+
+    #[test]
+    fn rank_regressed_to_not_found() {
+        let change = RankChange {
+            from: Some(4),
+            to: None,
+        };
+        assert!(change.regressed());
+        assert!(!change.improved());
+    }
+
+    #[test]
+    fn category_diffs_skip_empty_categories() {
+        let comparison = Comparison::new(
+            &test_case(None),
+            response(10, SUTTAPLEX_MN_FIRST_THREE_JSON),
+            response(20, SUTTAPLEX_MN3_MN1_JSON),
+        );
+
+        assert_eq!(
+            comparison.category_diffs(),
+            vec![CategoryDiff {
+                category: "suttaplex",
+                gained: vec![String::from("mn4")],
+                lost: vec![String::from("mn2")],
+                overlap: 0.5,
+            }]
+        );
+    }
+
+    #[test]
+    fn category_diff_ignores_duplicates() {
+        let primary = vec![String::from("PTS SN ii 1"), String::from("PTS SN ii 1")];
+        let secondary = vec![String::from("PTS SN ii 1")];
+
+        let diff = CategoryDiff::new("volpage", &primary, &secondary).unwrap();
+
+        assert_eq!(diff.overlap, 1.0);
+        assert!(!diff.changed());
+    }
+
+    #[test]
+    fn display_same() {
+        let comparison = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN1_JSON),
+            response(20, SUTTAPLEX_MN1_JSON),
+        );
+
+        assert_eq!(
+            comparison.to_string(),
+            message(&["SAME    PASSED  10ms   PASSED  20ms   Find suttaplex mn1"])
+        );
+    }
+
+    #[test]
+    fn display_rank_moved_and_results_changed() {
+        let comparison = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN_FIRST_THREE_JSON),
+            response(20, SUTTAPLEX_MN3_MN1_JSON),
+        );
+
+        assert_eq!(
+            comparison.to_string(),
+            message(&[
+                "SAME    PASSED  10ms   PASSED  20ms   Find suttaplex mn1",
+                "  Rank of Suttaplex hit mn1 moved from 1 to 2",
+                "  suttaplex 50% overlap, gained mn4, lost mn2",
+            ])
+        );
+    }
+
+    #[test]
+    fn display_changed() {
+        let comparison = Comparison::new(
+            &test_case(expect_mn1()),
+            response(10, SUTTAPLEX_MN1_JSON),
+            response(20, NO_RESULTS_JSON),
+        );
+
+        assert_eq!(
+            comparison.to_string(),
+            message(&[
+                "CHANGED PASSED  10ms   FAILED  20ms   Find suttaplex mn1",
+                "  Rank of Suttaplex hit mn1 moved from 1 to not found",
+                "  suttaplex 0% overlap, lost mn1",
+            ])
+        );
+    }
+}
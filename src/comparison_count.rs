@@ -0,0 +1,156 @@
+use crate::comparison::Comparison;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonCount {
+    compared: usize,
+    changed: usize,
+    improved: usize,
+    regressed: usize,
+    overlaps: Vec<(&'static str, Vec<f64>)>,
+}
+
+impl ComparisonCount {
+    pub fn new() -> Self {
+        Self {
+            compared: 0,
+            changed: 0,
+            improved: 0,
+            regressed: 0,
+            overlaps: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, comparison: &Comparison) {
+        self.compared += 1;
+        if comparison.outcome_changed() {
+            self.changed += 1;
+        }
+        if let Some(change) = comparison.rank_change() {
+            if change.improved() {
+                self.improved += 1;
+            }
+            if change.regressed() {
+                self.regressed += 1;
+            }
+        }
+        for diff in comparison.category_diffs() {
+            self.add_overlap(diff.category, diff.overlap);
+        }
+    }
+
+    fn add_overlap(&mut self, category: &'static str, overlap: f64) {
+        match self.overlaps.iter_mut().find(|(name, _)| *name == category) {
+            Some((_, overlaps)) => overlaps.push(overlap),
+            None => self.overlaps.push((category, vec![overlap])),
+        }
+    }
+
+    fn mean_overlaps(&self) -> Vec<String> {
+        self.overlaps
+            .iter()
+            .map(|(category, overlaps)| {
+                let mean = overlaps.iter().sum::<f64>() / overlaps.len() as f64;
+                format!("{category} {:.0}%", mean * 100.0)
+            })
+            .collect()
+    }
+}
+
+impl Display for ComparisonCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} compared, {} changed outcome, {} ranks improved, {} ranks regressed",
+            self.compared, self.changed, self.improved, self.regressed
+        )?;
+        if !self.overlaps.is_empty() {
+            write!(f, "\nMean overlap: {}", self.mean_overlaps().join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected::Expected;
+    use crate::identifiers::{SearchResultKey, SuttaplexUid};
+    use crate::test_case::{Origin, TestCase};
+    use crate::test_json::{NO_RESULTS_JSON, SUTTAPLEX_MN_FIRST_THREE_JSON, SUTTAPLEX_MN1_JSON};
+    use crate::timed_response::TimedResponse;
+    use std::time::Duration;
+
+    fn test_case() -> TestCase {
+        TestCase {
+            description: String::from("Find suttaplex mn3"),
+            query: String::from("mn3"),
+            limit: 50,
+            site_language: String::from("en"),
+            restrict: String::from("all"),
+            selected_languages: vec![String::from("en")],
+            match_partial: false,
+            expected: Some(Expected::Unranked {
+                key: SearchResultKey::Suttaplex {
+                    uid: SuttaplexUid::from("mn3"),
+                },
+            }),
+            tags: Vec::new(),
+            group: None,
+            origin: Origin::Suite,
+        }
+    }
+
+    fn response(json: &str) -> TimedResponse {
+        TimedResponse {
+            elapsed: Duration::from_millis(10),
+            json: Ok(String::from(json)),
+            body: None,
+        }
+    }
+
+    #[test]
+    fn initialise_comparison_count() {
+        assert_eq!(
+            ComparisonCount::new().to_string(),
+            "0 compared, 0 changed outcome, 0 ranks improved, 0 ranks regressed"
+        );
+    }
+
+    #[test]
+    fn add_improved_and_changed() {
+        let mut count = ComparisonCount::new();
+        count.add(&Comparison::new(
+            &test_case(),
+            response(SUTTAPLEX_MN1_JSON),
+            response(SUTTAPLEX_MN_FIRST_THREE_JSON),
+        ));
+
+        assert_eq!(
+            count.to_string(),
+            "1 compared, 1 changed outcome, 1 ranks improved, 0 ranks regressed\n\
+            Mean overlap: suttaplex 33%"
+        );
+    }
+
+    #[test]
+    fn mean_overlap_across_comparisons() {
+        let mut count = ComparisonCount::new();
+        count.add(&Comparison::new(
+            &test_case(),
+            response(SUTTAPLEX_MN_FIRST_THREE_JSON),
+            response(SUTTAPLEX_MN_FIRST_THREE_JSON),
+        ));
+        count.add(&Comparison::new(
+            &test_case(),
+            response(SUTTAPLEX_MN_FIRST_THREE_JSON),
+            response(NO_RESULTS_JSON),
+        ));
+
+        assert_eq!(
+            count.to_string(),
+            "2 compared, 1 changed outcome, 0 ranks improved, 1 ranks regressed\n\
+            Mean overlap: suttaplex 50%"
+        );
+    }
+}
@@ -8,6 +8,33 @@ pub struct Defaults {
     pub restrict: Option<String>,
     pub selected_languages: Option<Vec<String>>,
     pub match_partial: Option<bool>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Defaults {
+    pub fn or(&self, fallback: &Defaults) -> Defaults {
+        let mut tags = fallback.tags.clone();
+        for tag in &self.tags {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        Defaults {
+            limit: self.limit.or(fallback.limit),
+            site_language: self
+                .site_language
+                .clone()
+                .or(fallback.site_language.clone()),
+            restrict: self.restrict.clone().or(fallback.restrict.clone()),
+            selected_languages: self
+                .selected_languages
+                .clone()
+                .or(fallback.selected_languages.clone()),
+            match_partial: self.match_partial.or(fallback.match_partial),
+            tags,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -23,8 +50,39 @@ mod tests {
                 restrict: None,
                 selected_languages: None,
                 match_partial: None,
+                tags: Vec::new(),
             },
             Defaults::default()
         );
     }
+
+    #[test]
+    fn provided_defaults_take_precedence_over_fallback() {
+        let provided = Defaults {
+            limit: Some(50),
+            selected_languages: Some(vec!["de".to_string()]),
+            tags: vec!["german".to_string(), "smoke".to_string()],
+            ..Defaults::default()
+        };
+        let fallback = Defaults {
+            limit: Some(10),
+            site_language: Some("en".to_string()),
+            restrict: Some("all".to_string()),
+            selected_languages: Some(vec!["en".to_string(), "pli".to_string()]),
+            match_partial: Some(false),
+            tags: vec!["smoke".to_string()],
+        };
+
+        assert_eq!(
+            provided.or(&fallback),
+            Defaults {
+                limit: Some(50),
+                site_language: Some("en".to_string()),
+                restrict: Some("all".to_string()),
+                selected_languages: Some(vec!["de".to_string()]),
+                match_partial: Some(false),
+                tags: vec!["smoke".to_string(), "german".to_string()],
+            }
+        );
+    }
 }
@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Environment {
+    pub endpoint: String,
+    pub delay: Option<u64>,
+    pub timeout: Option<u64>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
+
+impl Environment {
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            endpoint,
+            ..Self::default()
+        }
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_endpoint_required() {
+        let environment: Environment =
+            toml::from_str(r#"endpoint = "http://localhost/api/search/instant""#).unwrap();
+        assert_eq!(
+            environment,
+            Environment::new(String::from("http://localhost/api/search/instant"))
+        );
+        assert_eq!(environment.timeout(), None);
+    }
+
+    #[test]
+    fn all_fields_provided() {
+        let environment: Environment = toml::from_str(
+            r#"
+            endpoint = "https://staging.suttacentral.net/api/search/instant"
+            delay = 100
+            timeout = 5000
+            headers = { Authorization = "Basic c3RhZ2luZw==" }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(environment.delay, Some(100));
+        assert_eq!(environment.timeout(), Some(Duration::from_millis(5000)));
+        assert_eq!(
+            environment.headers.get("Authorization").map(String::as_str),
+            Some("Basic c3RhZ2luZw==")
+        );
+    }
+}
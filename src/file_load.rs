@@ -1,58 +1,184 @@
+use crate::include::resolve_includes;
+use crate::overrides::Override;
 use crate::test_suite::TestSuite;
+use crate::test_table::load_test_tables;
 use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
 
-fn get_file_name(args: Vec<String>) -> Result<String> {
-    match args.len() {
-        1 => Err(anyhow!("No test suite file name provided")),
-        2 => Ok(args[1].clone()),
-        _ => Err(anyhow!(
-            "Too many arguments. Only one required: the suite file name"
+pub fn load_suite(
+    path: &Path,
+    environment: Option<&str>,
+    overrides: &[Override],
+) -> Result<TestSuite> {
+    let toml = std::fs::read_to_string(path)
+        .with_context(|| format!("Error reading file {}", path.display()))?;
+    let mut test_suite = TestSuite::load(path, toml.as_str(), overrides)?;
+    resolve_includes(path, &mut test_suite)?;
+    load_test_tables(path, &mut test_suite)?;
+    if let Some(name) = environment {
+        test_suite.select_environment(name)?;
+    }
+    test_suite.check_endpoint()?;
+    Ok(test_suite)
+}
+
+pub fn suite_paths(arguments: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for argument in arguments {
+        let pattern = argument.to_string_lossy();
+        let mut found = if argument.is_dir() {
+            suites_in_directory(argument)?
+        } else if is_glob(&pattern) {
+            suites_matching(&pattern)?
+        } else {
+            vec![argument.clone()]
+        };
+        paths.append(&mut found);
+    }
+    Ok(paths)
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '['])
+}
+
+fn suites_in_directory(directory: &Path) -> Result<Vec<PathBuf>> {
+    let entries = std::fs::read_dir(directory)
+        .with_context(|| format!("Error reading directory {}", directory.display()))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("Error reading directory {}", directory.display()))?
+            .path();
+        if path.is_file()
+            && path
+                .extension()
+                .is_some_and(|extension| extension == "toml")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    match paths.is_empty() {
+        true => Err(anyhow!(
+            "No test suite files found in directory {}",
+            directory.display()
         )),
+        false => Ok(paths),
     }
 }
 
-pub fn load_suite() -> Result<TestSuite> {
-    let args: Vec<String> = std::env::args().collect();
-    let file_name = get_file_name(args)?;
-    let toml = std::fs::read_to_string(file_name).context("Error reading file")?;
-    TestSuite::load_from_string(toml.as_str())
+fn suites_matching(pattern: &str) -> Result<Vec<PathBuf>> {
+    let mut paths = glob::glob(pattern)
+        .with_context(|| format!("Invalid pattern {pattern}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("Error matching pattern {pattern}"))?;
+    paths.sort();
+    match paths.is_empty() {
+        true => Err(anyhow!("No test suite files match {pattern}")),
+        false => Ok(paths),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::file_load::get_file_name;
+    use super::*;
+    use tempfile::TempDir;
+
+    const ENVIRONMENTS: &str = r#"
+        [settings]
+
+        [settings.environments.staging]
+        endpoint = "https://staging.suttacentral.net/api/search/instant"
+
+        [[test-case]]
+        description = "Find a sutta"
+        query = "metta"
+    "#;
 
     #[test]
-    fn no_arguments_provided() {
-        let args = vec![String::from("search-test")];
-        let error = get_file_name(args).unwrap_err();
-        assert_eq!(error.to_string(), "No test suite file name provided");
+    fn environment_chosen_without_default() {
+        let directory = TempDir::new().unwrap();
+        let path = directory.path().join("suite.toml");
+        std::fs::write(&path, ENVIRONMENTS).unwrap();
+
+        let test_suite = load_suite(&path, Some("staging"), &[]).unwrap();
+        assert_eq!(
+            test_suite.endpoint(),
+            "https://staging.suttacentral.net/api/search/instant"
+        );
+        assert!(load_suite(&path, None, &[]).is_err());
     }
 
     #[test]
-    fn file_name_provided() {
-        let args = vec![
-            String::from("search-test"),
-            String::from("test_cases/example.toml"),
+    fn unknown_environment_points_at_settings() {
+        let directory = TempDir::new().unwrap();
+        let path = directory.path().join("suite.toml");
+        std::fs::write(&path, ENVIRONMENTS).unwrap();
+
+        let error = load_suite(&path, Some("production"), &[]).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with(format!("{}:", path.display()).as_str())
+        );
+        assert!(
+            error
+                .to_string()
+                .contains("No environment named `production` in settings")
+        );
+    }
+
+    #[test]
+    fn missing_file_is_error() {
+        let error = load_suite(Path::new("no-such-suite.toml"), None, &[]).unwrap_err();
+        assert_eq!(error.to_string(), "Error reading file no-such-suite.toml");
+    }
+
+    fn examples(names: &[&str]) -> Vec<PathBuf> {
+        names
+            .iter()
+            .map(|name| Path::new("suite_examples").join(name))
+            .collect()
+    }
+
+    #[test]
+    fn plain_paths_are_kept_in_order() {
+        let arguments = vec![
+            PathBuf::from("suite_examples/volpage.toml"),
+            PathBuf::from("no-such-suite.toml"),
         ];
-        let file_name = get_file_name(args).unwrap();
+        assert_eq!(suite_paths(&arguments).unwrap(), arguments);
+    }
+
+    #[test]
+    fn directory_gives_its_suites() {
         assert_eq!(
-            file_name.to_string(),
-            String::from("test_cases/example.toml")
-        )
+            suite_paths(&[PathBuf::from("suite_examples")]).unwrap(),
+            examples(&[
+                "examples.toml",
+                "reference.toml",
+                "snowbird.toml",
+                "title.toml",
+                "volpage.toml"
+            ])
+        );
     }
 
     #[test]
-    fn too_many_arguments() {
-        let args = vec![
-            String::from("search-test"),
-            String::from("test_cases/example.toml"),
-            String::from("another-argument"),
-        ];
-        let error = get_file_name(args).unwrap_err();
+    fn glob_gives_matching_suites() {
+        assert_eq!(
+            suite_paths(&[PathBuf::from("suite_examples/[rs]*.toml")]).unwrap(),
+            examples(&["reference.toml", "snowbird.toml"])
+        );
+    }
+
+    #[test]
+    fn glob_without_matches_is_error() {
+        let error = suite_paths(&[PathBuf::from("suite_examples/*.json")]).unwrap_err();
         assert_eq!(
             error.to_string(),
-            "Too many arguments. Only one required: the suite file name"
+            "No test suite files match suite_examples/*.json"
         );
     }
 }
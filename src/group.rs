@@ -0,0 +1,13 @@
+use crate::defaults::Defaults;
+use crate::test_suite::TestCaseDetails;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Group {
+    pub name: String,
+    #[serde(default)]
+    pub defaults: Defaults,
+    #[serde(rename = "test-case")]
+    pub test_details: Vec<TestCaseDetails>,
+}
@@ -0,0 +1,325 @@
+use crate::summary::Summary;
+use crate::test_record::TestRecord;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const LATENCY_WINDOW: usize = 10;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RunRecord {
+    pub timestamp: DateTime<Utc>,
+    pub endpoint: String,
+    pub suite_path: String,
+    pub suite_hash: String,
+    pub tests: Vec<TestRecord>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct History {
+    runs: Vec<RunRecord>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TestTrend {
+    description: String,
+    summary: Summary,
+    streak: usize,
+    since: DateTime<Utc>,
+    latencies: Vec<u128>,
+}
+
+impl RunRecord {
+    pub fn new(suite: &Path, endpoint: String, tests: Vec<TestRecord>) -> Result<Self> {
+        let contents = std::fs::read(suite)
+            .with_context(|| format!("Error reading file {}", suite.display()))?;
+        Ok(Self {
+            timestamp: Utc::now(),
+            endpoint,
+            suite_path: suite.display().to_string(),
+            suite_hash: Self::hash(&contents),
+            tests,
+        })
+    }
+
+    fn hash(contents: &[u8]) -> String {
+        Sha256::digest(contents)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl History {
+    pub fn path(suite: &Path) -> PathBuf {
+        suite.with_extension("history.jsonl")
+    }
+
+    pub fn append(suite: &Path, run: &RunRecord) -> Result<()> {
+        let path = Self::path(suite);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Error opening history {}", path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(run)?)
+            .with_context(|| format!("Error writing history {}", path.display()))
+    }
+
+    pub fn load(suite: &Path) -> Result<Self> {
+        let path = Self::path(suite);
+        let jsonl = std::fs::read_to_string(&path)
+            .with_context(|| format!("Error reading history {}", path.display()))?;
+        Self::from_jsonl(jsonl.as_str())
+            .with_context(|| format!("Failed to parse history {}", path.display()))
+    }
+
+    fn from_jsonl(jsonl: &str) -> Result<Self> {
+        let runs = jsonl
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).with_context(|| format!("line {}", index + 1))
+            })
+            .collect::<Result<Vec<RunRecord>>>()?;
+        Ok(Self { runs })
+    }
+
+    pub fn trends(&self) -> Vec<TestTrend> {
+        match self.runs.last() {
+            Some(latest) => latest
+                .tests
+                .iter()
+                .map(|test| self.trend(test.description.as_str()))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn trend(&self, description: &str) -> TestTrend {
+        let records: Vec<(&DateTime<Utc>, &TestRecord)> = self
+            .runs
+            .iter()
+            .filter_map(|run| {
+                run.tests
+                    .iter()
+                    .find(|test| test.description == description)
+                    .map(|test| (&run.timestamp, test))
+            })
+            .collect();
+
+        let (_, latest) = records[records.len() - 1];
+        let streak: Vec<_> = records
+            .iter()
+            .rev()
+            .take_while(|(_, record)| record.summary == latest.summary)
+            .collect();
+        let (since, _) = streak[streak.len() - 1];
+
+        let latencies = records
+            .iter()
+            .skip(records.len().saturating_sub(LATENCY_WINDOW))
+            .map(|(_, record)| record.elapsed_ms)
+            .collect();
+
+        TestTrend {
+            description: String::from(description),
+            summary: latest.summary.clone(),
+            streak: streak.len(),
+            since: **since,
+            latencies,
+        }
+    }
+}
+
+impl TestTrend {
+    fn latency_trend(&self) -> String {
+        let first = self.latencies[0];
+        let last = self.latencies[self.latencies.len() - 1];
+        let mean = self.latencies.iter().sum::<u128>() / self.latencies.len() as u128;
+        format!("{first}ms -> {last}ms, mean {mean}ms")
+    }
+}
+
+impl Display for TestTrend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let streak = format!("x{}", self.streak);
+        let since = self.since.format("%Y-%m-%d %H:%M");
+        writeln!(
+            f,
+            "{:7} {streak:5} since {since}  {}",
+            self.summary.to_string(),
+            self.description
+        )?;
+        writeln!(f, "  latency {}", self.latency_trend())
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (Some(first), Some(last)) = (self.runs.first(), self.runs.last()) else {
+            return writeln!(f, "No runs recorded");
+        };
+        writeln!(
+            f,
+            "{} runs recorded from {} to {}, latest against endpoint {}",
+            self.runs.len(),
+            first.timestamp.format("%Y-%m-%d %H:%M"),
+            last.timestamp.format("%Y-%m-%d %H:%M"),
+            last.endpoint
+        )?;
+        writeln!(f)?;
+        for trend in self.trends() {
+            write!(f, "{trend}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn record(description: &str, summary: Summary, elapsed_ms: u128) -> TestRecord {
+        TestRecord {
+            description: String::from(description),
+            summary,
+            rank: None,
+            top: Vec::new(),
+            elapsed_ms,
+        }
+    }
+
+    fn run(day: u32, tests: Vec<TestRecord>) -> RunRecord {
+        RunRecord {
+            timestamp: Utc.with_ymd_and_hms(2026, 10, day, 9, 30, 0).unwrap(),
+            endpoint: String::from("http://localhost/api/search/instant"),
+            suite_path: String::from("examples.toml"),
+            suite_hash: String::from("abc"),
+            tests,
+        }
+    }
+
+    fn history() -> History {
+        History {
+            runs: vec![
+                run(1, vec![record("Find mn1", Summary::Failed, 100)]),
+                run(
+                    2,
+                    vec![
+                        record("Find mn1", Summary::Passed, 200),
+                        record("Find mn2", Summary::Passed, 50),
+                    ],
+                ),
+                run(
+                    3,
+                    vec![
+                        record("Find mn1", Summary::Passed, 300),
+                        record("Find mn2", Summary::Passed, 70),
+                    ],
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn history_path_is_next_to_suite() {
+        assert_eq!(
+            History::path(Path::new("suites/examples.toml")),
+            PathBuf::from("suites/examples.history.jsonl")
+        );
+    }
+
+    #[test]
+    fn hash_of_contents() {
+        assert_eq!(
+            RunRecord::hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn trend_with_change_of_state() {
+        assert_eq!(
+            history().trends()[0],
+            TestTrend {
+                description: String::from("Find mn1"),
+                summary: Summary::Passed,
+                streak: 2,
+                since: Utc.with_ymd_and_hms(2026, 10, 2, 9, 30, 0).unwrap(),
+                latencies: vec![100, 200, 300],
+            }
+        );
+    }
+
+    #[test]
+    fn trend_for_test_added_later() {
+        assert_eq!(
+            history().trends()[1],
+            TestTrend {
+                description: String::from("Find mn2"),
+                summary: Summary::Passed,
+                streak: 2,
+                since: Utc.with_ymd_and_hms(2026, 10, 2, 9, 30, 0).unwrap(),
+                latencies: vec![50, 70],
+            }
+        );
+    }
+
+    #[test]
+    fn display_history() {
+        assert_eq!(
+            history().to_string(),
+            "3 runs recorded from 2026-10-01 09:30 to 2026-10-03 09:30, latest against endpoint http://localhost/api/search/instant\n\
+            \n\
+            PASSED  x2    since 2026-10-02 09:30  Find mn1\n\
+            \x20 latency 100ms -> 300ms, mean 200ms\n\
+            PASSED  x2    since 2026-10-02 09:30  Find mn2\n\
+            \x20 latency 50ms -> 70ms, mean 60ms\n"
+        );
+    }
+
+    #[test]
+    fn display_empty_history() {
+        assert_eq!(
+            History { runs: Vec::new() }.to_string(),
+            "No runs recorded\n"
+        );
+    }
+
+    #[test]
+    fn parse_error_gives_line_number() {
+        let error = History::from_jsonl("\nnot json").unwrap_err();
+        assert_eq!(error.to_string(), "line 2");
+    }
+
+    #[test]
+    fn append_and_load() {
+        let directory = TempDir::new().unwrap();
+        let suite = directory.path().join("suite.toml");
+        std::fs::write(&suite, "[settings]").unwrap();
+
+        let first = RunRecord::new(&suite, String::from("http://localhost"), Vec::new()).unwrap();
+        let second = run(2, vec![record("Find mn1", Summary::Passed, 200)]);
+        History::append(&suite, &first).unwrap();
+        History::append(&suite, &second).unwrap();
+
+        let history = History::load(&suite).unwrap();
+
+        assert_eq!(
+            history,
+            History {
+                runs: vec![first, second]
+            }
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use crate::markup::escape;
+use crate::result_count::ResultCount;
+use crate::search_results::SearchResults;
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+const STYLE: &str = r#"
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border-bottom: 1px solid #ddd; padding: 0.4em; text-align: left; vertical-align: top; }
+tr.passed td.summary { color: #1a7f37; }
+tr.failed td.summary { color: #cf222e; }
+tr.error td.summary { color: #9a6700; }
+td.elapsed { text-align: right; }
+mark { font-weight: bold; }
+.missing { color: #cf222e; }
+"#;
+
+const SCRIPT: &str = r#"
+function filterRows() {
+  const text = document.getElementById('filter').value.toLowerCase();
+  const summary = document.getElementById('summary').value;
+  for (const row of document.querySelectorAll('tbody tr')) {
+    const matches = row.textContent.toLowerCase().includes(text)
+      && (summary === '' || row.className === summary);
+    row.hidden = !matches;
+  }
+}
+"#;
+
+#[derive(Debug)]
+pub struct HtmlReport {
+    name: String,
+    endpoint: String,
+    results: Vec<TestResult>,
+}
+
+impl HtmlReport {
+    pub fn new(name: String, endpoint: String) -> Self {
+        Self {
+            name,
+            endpoint,
+            results: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, result: &TestResult) {
+        self.results.push(result.clone());
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_html())
+            .with_context(|| format!("Error writing HTML report {}", path.display()))
+    }
+
+    fn count(&self) -> ResultCount {
+        let mut count = ResultCount::new();
+        for result in &self.results {
+            count.add(&result.outcome);
+        }
+        count
+    }
+
+    fn milliseconds(elapsed: Duration) -> String {
+        format!("{}ms", elapsed.as_millis())
+    }
+
+    fn ranked_list(results: &SearchResults) -> String {
+        let expected = results.expected_id();
+        let items: String = results
+            .ids()
+            .iter()
+            .map(|id| match *id == expected {
+                true => format!("<li><mark>{}</mark></li>", escape(id)),
+                false => format!("<li>{}</li>", escape(id)),
+            })
+            .collect();
+        let missing = match results.found() {
+            true => String::new(),
+            false => format!(
+                "<p class=\"missing\">{} is missing from the results</p>",
+                escape(expected.as_str())
+            ),
+        };
+        format!(
+            "<p>{} results</p>{missing}<ol>{items}</ol>",
+            escape(results.category())
+        )
+    }
+
+    fn details(result: &TestResult, summary: &Summary) -> String {
+        if *summary == Summary::Passed {
+            return String::new();
+        }
+        let detail = result.detail_line().unwrap_or_default();
+        let ranked_list = result
+            .outcome
+            .results()
+            .map(Self::ranked_list)
+            .unwrap_or_default();
+        format!(
+            "<details><summary>{}</summary>{ranked_list}</details>",
+            escape(detail.as_str())
+        )
+    }
+
+    fn row(result: &TestResult) -> String {
+        let summary = Summary::from(&result.outcome);
+        let class = summary.to_string().to_lowercase();
+        format!(
+            "<tr class=\"{class}\"><td class=\"summary\">{summary}</td><td class=\"elapsed\">{}</td><td>{}{}</td></tr>\n",
+            Self::milliseconds(result.elapsed),
+            escape(result.test_case.description.as_str()),
+            Self::details(result, &summary)
+        )
+    }
+
+    pub fn to_html(&self) -> String {
+        let name = escape(self.name.as_str());
+        let total: Duration = self.results.iter().map(|result| result.elapsed).sum();
+        let rows: String = self.results.iter().map(Self::row).collect();
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Search test report: {name}</title>
+<style>{STYLE}</style>
+<script>{SCRIPT}</script>
+</head>
+<body>
+<h1>Search test report: {name}</h1>
+<p>Endpoint: {}</p>
+<p>{}</p>
+<p>Total time: {}</p>
+<p>
+<input id="filter" type="search" placeholder="Filter tests" oninput="filterRows()">
+<select id="summary" onchange="filterRows()">
+<option value="">All</option>
+<option value="passed">Passed</option>
+<option value="failed">Failed</option>
+<option value="error">Error</option>
+</select>
+</p>
+<table>
+<thead><tr><th>Outcome</th><th>Time</th><th>Description</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+</body>
+</html>
+"#,
+            escape(self.endpoint.as_str()),
+            self.count(),
+            Self::milliseconds(total),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::rank::Rank;
+    use crate::test_fixtures::test_case;
+
+    fn report() -> HtmlReport {
+        let mut report = HtmlReport::new(
+            String::from("examples"),
+            String::from("http://localhost/api/search/instant?a&b"),
+        );
+        report.add(&TestResult {
+            test_case: test_case("Search is <successful>"),
+            elapsed: Duration::from_millis(679),
+            outcome: Outcome::Success,
+            body: None,
+        });
+        report.add(&TestResult {
+            test_case: test_case("Expecting top rank"),
+            elapsed: Duration::from_millis(76),
+            outcome: Outcome::Ranked {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn2"),
+                    results: vec![SuttaplexUid::from("mn1"), SuttaplexUid::from("mn2")],
+                },
+                rank: Rank::TooLow {
+                    minimum: 1,
+                    actual: 2,
+                },
+            },
+            body: None,
+        });
+        report.add(&TestResult {
+            test_case: test_case("Find suttaplex mn3"),
+            elapsed: Duration::from_millis(21),
+            outcome: Outcome::NotFound {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn3"),
+                    results: vec![SuttaplexUid::from("mn1")],
+                },
+            },
+            body: None,
+        });
+        report
+    }
+
+    #[test]
+    fn header_has_endpoint_counts_and_time() {
+        let html = report().to_html();
+        assert!(html.contains("<p>Endpoint: http://localhost/api/search/instant?a&amp;b</p>"));
+        assert!(html.contains("<p>1 passed, 2 failed, 0 encountered an error</p>"));
+        assert!(html.contains("<p>Total time: 776ms</p>"));
+    }
+
+    #[test]
+    fn passed_row_has_no_details() {
+        assert!(report().to_html().contains(
+            "<tr class=\"passed\"><td class=\"summary\">PASSED</td><td class=\"elapsed\">679ms</td><td>Search is &lt;successful&gt;</td></tr>"
+        ));
+    }
+
+    #[test]
+    fn failure_highlights_expected_result() {
+        assert!(report().to_html().contains(
+            "<tr class=\"failed\"><td class=\"summary\">FAILED</td><td class=\"elapsed\">76ms</td><td>Expecting top rank\
+            <details><summary>Expected Suttaplex hit mn2 to have minimum rank of 1 but it was found at rank 2</summary>\
+            <p>Suttaplex results</p><ol><li>mn1</li><li><mark>mn2</mark></li></ol></details></td></tr>"
+        ));
+    }
+
+    #[test]
+    fn failure_marks_expected_result_missing() {
+        assert!(report().to_html().contains(
+            "<details><summary>Suttaplex hit mn3 not found in search results</summary>\
+            <p>Suttaplex results</p><p class=\"missing\">mn3 is missing from the results</p>\
+            <ol><li>mn1</li></ol></details>"
+        ));
+    }
+}
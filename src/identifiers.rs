@@ -1,8 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub struct TextUrl(String);
 
 impl Display for TextUrl {
@@ -17,7 +17,7 @@ impl From<&str> for TextUrl {
     }
 }
 
-#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub struct DictionaryUrl(String);
 
 impl Display for DictionaryUrl {
@@ -32,7 +32,7 @@ impl From<&str> for DictionaryUrl {
     }
 }
 
-#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub struct SuttaplexUid(String);
 
 impl Display for SuttaplexUid {
@@ -47,7 +47,7 @@ impl From<&str> for SuttaplexUid {
     }
 }
 
-#[derive(Clone, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub struct VolpageReference(String);
 
 impl Display for VolpageReference {
@@ -62,7 +62,8 @@ impl From<&str> for VolpageReference {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum SearchResultKey {
     Text { url: TextUrl },
     Dictionary { url: DictionaryUrl },
@@ -0,0 +1,288 @@
+use crate::defaults::Defaults;
+use crate::overrides::apply_overrides;
+use crate::source_location::parse_error;
+use crate::test_suite::{TestCaseDetails, TestSuite, parse_located};
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Included {
+    #[serde(default)]
+    include: Vec<PathBuf>,
+    #[serde(default)]
+    pub defaults: Defaults,
+    #[serde(default, rename = "test-case")]
+    pub test_details: Vec<TestCaseDetails>,
+}
+
+impl Included {
+    fn merge(&mut self, other: Included) {
+        self.defaults = self.defaults.or(&other.defaults);
+        self.test_details.splice(0..0, other.test_details);
+    }
+
+    fn parse(path: &Path, source: &str) -> Result<Included> {
+        let source = apply_overrides(source, &[], |name| std::env::var(name).ok())
+            .map_err(|error| parse_error(path, source, error))?;
+        let (mut included, locations) = parse_located::<Included>(path, source.as_str())?;
+        for (details, location) in included.test_details.iter_mut().zip(locations) {
+            details.location = Some(location);
+        }
+        Ok(included)
+    }
+}
+
+pub fn resolve_includes(suite_path: &Path, test_suite: &mut TestSuite) -> Result<()> {
+    let mut chain = vec![suite_path.to_path_buf()];
+    let included = load_all(suite_path, &test_suite.includes(), &mut chain)?;
+    test_suite.add_included(included);
+    Ok(())
+}
+
+fn load_all(parent: &Path, includes: &[PathBuf], chain: &mut Vec<PathBuf>) -> Result<Included> {
+    let directory = parent.parent().unwrap_or(Path::new(""));
+    let mut combined = Included::default();
+    for include in includes {
+        let mut included = load_included(&directory.join(include), chain)?;
+        included.merge(combined);
+        combined = included;
+    }
+    Ok(combined)
+}
+
+fn load_included(path: &Path, chain: &mut Vec<PathBuf>) -> Result<Included> {
+    if is_circular(path, chain) {
+        let files: Vec<String> = chain
+            .iter()
+            .chain([&path.to_path_buf()])
+            .map(|file| file.display().to_string())
+            .collect();
+        return Err(anyhow!("Circular include: {}", files.join(" -> ")));
+    }
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("Error reading included file {}", path.display()))?;
+    let mut included = Included::parse(path, source.as_str())
+        .with_context(|| format!("Error in included file {}", path.display()))?;
+    chain.push(path.to_path_buf());
+    let nested = load_all(path, &included.include, chain)?;
+    chain.pop();
+    included.merge(nested);
+    Ok(included)
+}
+
+fn is_circular(path: &Path, chain: &[PathBuf]) -> bool {
+    match path.canonicalize() {
+        Ok(canonical) => chain
+            .iter()
+            .any(|file| file.canonicalize().is_ok_and(|file| file == canonical)),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn directory(files: &[(&str, &str)]) -> TempDir {
+        let directory = TempDir::new().unwrap();
+        std::fs::create_dir_all(directory.path().join("shared")).unwrap();
+        for (file, contents) in files {
+            std::fs::write(directory.path().join(file), contents).unwrap();
+        }
+        directory
+    }
+
+    fn suite(directory: &Path, source: &str) -> Result<TestSuite> {
+        let path = directory.join("suite.toml");
+        std::fs::write(&path, source).unwrap();
+        let mut test_suite = TestSuite::load_from_string(source)?;
+        resolve_includes(&path, &mut test_suite)?;
+        Ok(test_suite)
+    }
+
+    const DEFAULTS: &str = r#"
+        [defaults]
+        limit = 10
+        site-language = "en"
+        restrict = "all"
+        match-partial = false
+        selected-languages = ["en", "pli"]
+        tags = ["shared"]
+    "#;
+
+    #[test]
+    fn defaults_come_from_included_file() {
+        let directory = directory(&[("shared/defaults.toml", DEFAULTS)]);
+        let test_suite = suite(
+            directory.path(),
+            r#"
+            include = ["shared/defaults.toml"]
+
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            limit = 50
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+
+            [[test-case]]
+            description = "Find a German sutta"
+            query = "metta"
+            selected-languages = ["de"]
+            "#,
+        )
+        .unwrap();
+
+        let test_cases: Vec<_> = test_suite
+            .test_cases()
+            .map(|test_case| test_case.unwrap())
+            .collect();
+        assert_eq!(test_cases[0].limit, 50);
+        assert_eq!(test_cases[0].site_language, "en");
+        assert_eq!(test_cases[0].selected_languages, vec!["en", "pli"]);
+        assert_eq!(test_cases[0].tags, vec!["shared"]);
+        assert_eq!(test_cases[1].selected_languages, vec!["de"]);
+    }
+
+    #[test]
+    fn test_cases_and_nested_includes() {
+        let directory = directory(&[
+            ("shared/defaults.toml", DEFAULTS),
+            (
+                "shared/common.toml",
+                r#"
+                    include = ["defaults.toml"]
+
+                    [defaults]
+                    limit = 20
+
+                    [[test-case]]
+                    description = "Shared test"
+                    query = "dosa"
+                    "#,
+            ),
+        ]);
+        let test_suite = suite(
+            directory.path(),
+            r#"
+            include = ["shared/common.toml"]
+
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Local test"
+            query = "metta"
+            "#,
+        )
+        .unwrap();
+
+        let test_cases: Vec<_> = test_suite
+            .test_cases()
+            .map(|test_case| test_case.unwrap())
+            .collect();
+        let descriptions: Vec<&str> = test_cases
+            .iter()
+            .map(|test_case| test_case.description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["Shared test", "Local test"]);
+        assert_eq!(test_cases[1].limit, 20);
+        assert_eq!(test_cases[1].restrict, "all");
+    }
+
+    #[test]
+    fn later_includes_take_precedence() {
+        let directory = directory(&[
+            ("shared/defaults.toml", DEFAULTS),
+            (
+                "shared/german.toml",
+                "[defaults]\nselected-languages = [\"de\"]\n",
+            ),
+        ]);
+        let test_suite = suite(
+            directory.path(),
+            r#"
+            include = ["shared/defaults.toml", "shared/german.toml"]
+
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Find a German sutta"
+            query = "metta"
+            "#,
+        )
+        .unwrap();
+
+        let test_case = test_suite.test_cases().next().unwrap().unwrap();
+        assert_eq!(test_case.selected_languages, vec!["de"]);
+        assert_eq!(test_case.limit, 10);
+    }
+
+    #[test]
+    fn circular_include_is_error() {
+        let directory = directory(&[
+            ("shared/first.toml", "include = [\"second.toml\"]\n"),
+            ("shared/second.toml", "include = [\"first.toml\"]\n"),
+        ]);
+        let error = suite(
+            directory.path(),
+            r#"
+            include = ["shared/first.toml"]
+
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+            "#,
+        )
+        .unwrap_err();
+
+        let shared = directory.path().join("shared");
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Circular include: {} -> {} -> {} -> {}",
+                directory.path().join("suite.toml").display(),
+                shared.join("first.toml").display(),
+                shared.join("second.toml").display(),
+                shared.join("first.toml").display()
+            )
+        );
+    }
+
+    #[test]
+    fn missing_include_is_error() {
+        let directory = directory(&[]);
+        let error = suite(
+            directory.path(),
+            r#"
+            include = ["shared/no-such-file.toml"]
+
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Error reading included file {}",
+                directory.path().join("shared/no-such-file.toml").display()
+            )
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::identifiers::SearchResultKey;
+use crate::outcome::Outcome;
+use crate::rank::Rank;
+use crate::reporter::Reporter;
+use crate::result_count::ResultCount;
+use crate::search_error::ErrorKind;
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use crate::test_suite::TestSuite;
+use anyhow::Result;
+use serde::Serialize;
+use std::io::Write;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum JsonLine<'a> {
+    #[serde(rename_all = "kebab-case")]
+    Suite {
+        path: String,
+        endpoint: String,
+        delay: u64,
+        total: usize,
+    },
+    #[serde(rename_all = "kebab-case")]
+    Test {
+        description: &'a str,
+        group: Option<&'a str>,
+        request: JsonRequest<'a>,
+        summary: Summary,
+        outcome: &'static str,
+        expected: Option<SearchResultKey>,
+        rank: Option<usize>,
+        ranking: Option<&'a Rank>,
+        elapsed_ms: u128,
+        error: Option<&'a str>,
+        error_kind: Option<ErrorKind>,
+    },
+    #[serde(rename_all = "kebab-case")]
+    Note { text: &'a str },
+    #[serde(rename_all = "kebab-case")]
+    Summary {
+        #[serde(flatten)]
+        count: &'a ResultCount,
+    },
+    #[serde(rename_all = "kebab-case")]
+    Total {
+        #[serde(flatten)]
+        count: &'a ResultCount,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct JsonRequest<'a> {
+    query: &'a str,
+    limit: usize,
+    site_language: &'a str,
+    restrict: &'a str,
+    selected_languages: &'a [String],
+    match_partial: bool,
+}
+
+impl<'a> From<&'a TestResult> for JsonLine<'a> {
+    fn from(result: &'a TestResult) -> Self {
+        let test_case = &result.test_case;
+        let (error, error_kind) = match &result.outcome {
+            Outcome::Error { kind, message } => (Some(message.as_str()), Some(*kind)),
+            _ => (None, None),
+        };
+        JsonLine::Test {
+            description: test_case.description.as_str(),
+            group: test_case.group.as_deref(),
+            request: JsonRequest {
+                query: test_case.query.as_str(),
+                limit: test_case.limit,
+                site_language: test_case.site_language.as_str(),
+                restrict: test_case.restrict.as_str(),
+                selected_languages: &test_case.selected_languages,
+                match_partial: test_case.match_partial,
+            },
+            summary: Summary::from(&result.outcome),
+            outcome: result.outcome.kind(),
+            expected: test_case.expected.as_ref().map(|expected| expected.key()),
+            rank: result.outcome.results().and_then(|results| results.rank()),
+            ranking: result.outcome.rank(),
+            elapsed_ms: result.elapsed.as_millis(),
+            error,
+            error_kind,
+        }
+    }
+}
+
+pub struct JsonReporter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonReporter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    fn write_line(&mut self, line: &JsonLine) -> Result<()> {
+        writeln!(self.writer, "{}", serde_json::to_string(line)?)?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    fn begin(&mut self, suite_path: &Path, test_suite: &TestSuite, total: usize) -> Result<()> {
+        self.write_line(&JsonLine::Suite {
+            path: suite_path.display().to_string(),
+            endpoint: test_suite.endpoint(),
+            delay: test_suite.delay(),
+            total,
+        })
+    }
+
+    fn result(&mut self, result: &TestResult) -> Result<()> {
+        self.write_line(&JsonLine::from(result))
+    }
+
+    fn note(&mut self, note: &str) -> Result<()> {
+        self.write_line(&JsonLine::Note {
+            text: note.trim_end(),
+        })
+    }
+
+    fn info(&mut self, _info: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn finish(&mut self, count: &ResultCount) -> Result<()> {
+        self.write_line(&JsonLine::Summary { count })
+    }
+
+    fn total(&mut self, count: &ResultCount) -> Result<()> {
+        self.write_line(&JsonLine::Total { count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected::Expected;
+    use crate::identifiers::SuttaplexUid;
+    use crate::search_results::SearchResults;
+    use crate::test_case::TestCase;
+    use crate::test_fixtures;
+    use std::time::Duration;
+
+    fn test_case(expected: Option<Expected>) -> TestCase {
+        TestCase {
+            query: String::from("mn2"),
+            selected_languages: vec![String::from("en"), String::from("pli")],
+            expected,
+            ..test_fixtures::test_case("Find mn2")
+        }
+    }
+
+    fn json(result: &TestResult) -> String {
+        serde_json::to_string(&JsonLine::from(result)).unwrap()
+    }
+
+    #[test]
+    fn ranked_result() {
+        let result = TestResult {
+            test_case: test_case(Some(Expected::Ranked {
+                key: SearchResultKey::Suttaplex {
+                    uid: SuttaplexUid::from("mn2"),
+                },
+                min_rank: 1,
+            })),
+            elapsed: Duration::from_millis(76),
+            outcome: Outcome::Ranked {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn2"),
+                    results: vec![SuttaplexUid::from("mn1"), SuttaplexUid::from("mn2")],
+                },
+                rank: Rank::TooLow {
+                    minimum: 1,
+                    actual: 2,
+                },
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            json(&result),
+            r#"{"type":"test","description":"Find mn2","group":null,"request":{"query":"mn2","limit":10,"site-language":"en","restrict":"all","selected-languages":["en","pli"],"match-partial":false},"summary":"failed","outcome":"ranked","expected":{"suttaplex":{"uid":"mn2"}},"rank":2,"ranking":{"status":"too-low","minimum":1,"actual":2},"elapsed-ms":76,"error":null,"error-kind":null}"#
+        );
+    }
+
+    #[test]
+    fn error_result() {
+        let result = TestResult {
+            test_case: test_case(None),
+            elapsed: Duration::from_millis(55),
+            outcome: Outcome::Error {
+                kind: ErrorKind::HttpStatus,
+                message: String::from("Expected status code to be 200 OK but got 502 Bad Gateway"),
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            json(&result),
+            r#"{"type":"test","description":"Find mn2","group":null,"request":{"query":"mn2","limit":10,"site-language":"en","restrict":"all","selected-languages":["en","pli"],"match-partial":false},"summary":"error","outcome":"error","expected":null,"rank":null,"ranking":null,"elapsed-ms":55,"error":"Expected status code to be 200 OK but got 502 Bad Gateway","error-kind":"http-status"}"#
+        );
+    }
+
+    #[test]
+    fn stream_of_lines() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Find mn2"
+            query = "mn2"
+            "#,
+        )
+        .unwrap();
+        let result = TestResult {
+            test_case: test_case(None),
+            elapsed: Duration::from_millis(5),
+            outcome: Outcome::Success,
+            body: None,
+        };
+        let mut count = ResultCount::new();
+        count.add(&Outcome::Success);
+
+        let mut output = Vec::new();
+        let mut reporter = JsonReporter::new(&mut output);
+        reporter.begin(Path::new("suite.toml"), &suite, 1).unwrap();
+        reporter.result(&result).unwrap();
+        reporter.info("Total time 0.01s").unwrap();
+        reporter.note("No expectations needed blessing\n").unwrap();
+        reporter.finish(&count).unwrap();
+        reporter.total(&count).unwrap();
+
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let types: Vec<&str> = lines
+            .iter()
+            .map(|line| line["type"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(types, vec!["suite", "test", "note", "summary", "total"]);
+        assert_eq!(lines[0]["path"], "suite.toml");
+        assert_eq!(lines[0]["total"], 1);
+        assert_eq!(lines[2]["text"], "No expectations needed blessing");
+        assert_eq!(lines[3]["passed"], 1);
+        assert_eq!(lines[3]["failed"], 0);
+        assert_eq!(lines[3]["error"], 0);
+        assert_eq!(lines[3]["http-status-error"], 0);
+        assert_eq!(lines[4]["passed"], 1);
+    }
+}
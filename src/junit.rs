@@ -0,0 +1,183 @@
+use crate::markup::escape;
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct JUnitReport {
+    name: String,
+    endpoint: String,
+    results: Vec<TestResult>,
+}
+
+impl JUnitReport {
+    pub fn new(name: String, endpoint: String) -> Self {
+        Self {
+            name,
+            endpoint,
+            results: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, result: &TestResult) {
+        self.results.push(result.clone());
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_xml())
+            .with_context(|| format!("Error writing JUnit report {}", path.display()))
+    }
+
+    fn count(&self, summary: Summary) -> usize {
+        self.results
+            .iter()
+            .filter(|result| Summary::from(&result.outcome) == summary)
+            .count()
+    }
+
+    fn seconds(elapsed: Duration) -> String {
+        format!("{:.3}", elapsed.as_secs_f64())
+    }
+
+    fn test_case(&self, result: &TestResult) -> String {
+        let opening = format!(
+            r#"<testcase name="{}" classname="{}" time="{}""#,
+            escape(result.test_case.description.as_str()),
+            escape(self.name.as_str()),
+            Self::seconds(result.elapsed)
+        );
+        let element = match Summary::from(&result.outcome) {
+            Summary::Passed => None,
+            Summary::Failed => Some("failure"),
+            Summary::Error => Some("error"),
+        };
+        match (element, result.detail_line()) {
+            (Some(element), Some(detail)) => {
+                let detail = escape(detail.as_str());
+                format!(
+                    "    {opening}>\n      <{element} message=\"{detail}\">{detail}</{element}>\n    </testcase>\n"
+                )
+            }
+            _ => format!("    {opening}/>\n"),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        let tests = self.results.len();
+        let failures = self.count(Summary::Failed);
+        let errors = self.count(Summary::Error);
+        let time = Self::seconds(self.results.iter().map(|result| result.elapsed).sum());
+        let name = escape(self.name.as_str());
+        let attributes = format!(
+            r#"name="{name}" tests="{tests}" failures="{failures}" errors="{errors}" time="{time}""#
+        );
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(format!("<testsuites {attributes}>\n").as_str());
+        xml.push_str(format!("  <testsuite {attributes}>\n").as_str());
+        xml.push_str("    <properties>\n");
+        xml.push_str(format!("      <property name=\"suite\" value=\"{name}\"/>\n").as_str());
+        xml.push_str(
+            format!(
+                "      <property name=\"endpoint\" value=\"{}\"/>\n",
+                escape(self.endpoint.as_str())
+            )
+            .as_str(),
+        );
+        xml.push_str("    </properties>\n");
+        for result in &self.results {
+            xml.push_str(self.test_case(result).as_str());
+        }
+        xml.push_str("  </testsuite>\n");
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::search_error::ErrorKind;
+    use crate::search_results::SearchResults;
+    use crate::test_fixtures::test_case;
+
+    fn report() -> JUnitReport {
+        JUnitReport::new(
+            String::from("examples"),
+            String::from("http://localhost/api/search/instant"),
+        )
+    }
+
+    #[test]
+    fn empty_report() {
+        assert_eq!(
+            report().to_xml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="examples" tests="0" failures="0" errors="0" time="0.000">
+  <testsuite name="examples" tests="0" failures="0" errors="0" time="0.000">
+    <properties>
+      <property name="suite" value="examples"/>
+      <property name="endpoint" value="http://localhost/api/search/instant"/>
+    </properties>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn passed_failed_and_error() {
+        let mut report = report();
+        report.add(&TestResult {
+            test_case: test_case("Search is successful"),
+            elapsed: Duration::from_millis(679),
+            outcome: Outcome::Success,
+            body: None,
+        });
+        report.add(&TestResult {
+            test_case: test_case("Find suttaplex mn1"),
+            elapsed: Duration::from_millis(21),
+            outcome: Outcome::NotFound {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn1"),
+                    results: vec![],
+                },
+            },
+            body: None,
+        });
+        report.add(&TestResult {
+            test_case: test_case("Causes <internal> server error"),
+            elapsed: Duration::from_millis(1500),
+            outcome: Outcome::Error {
+                kind: ErrorKind::HttpStatus,
+                message: String::from("Expected status code to be 200 OK but got 502 Bad Gateway"),
+            },
+            body: None,
+        });
+
+        assert_eq!(
+            report.to_xml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="examples" tests="3" failures="1" errors="1" time="2.200">
+  <testsuite name="examples" tests="3" failures="1" errors="1" time="2.200">
+    <properties>
+      <property name="suite" value="examples"/>
+      <property name="endpoint" value="http://localhost/api/search/instant"/>
+    </properties>
+    <testcase name="Search is successful" classname="examples" time="0.679"/>
+    <testcase name="Find suttaplex mn1" classname="examples" time="0.021">
+      <failure message="Suttaplex hit mn1 not found in search results">Suttaplex hit mn1 not found in search results</failure>
+    </testcase>
+    <testcase name="Causes &lt;internal&gt; server error" classname="examples" time="1.500">
+      <error message="Expected status code to be 200 OK but got 502 Bad Gateway">Expected status code to be 200 OK but got 502 Bad Gateway</error>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
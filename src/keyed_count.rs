@@ -0,0 +1,146 @@
+use crate::result_count::ResultCount;
+use crate::test_case::TestCase;
+use crate::test_result::TestResult;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone)]
+pub struct KeyedCount {
+    heading: &'static str,
+    keys: fn(&TestCase) -> Vec<String>,
+    sorted: bool,
+    counts: Vec<(String, ResultCount)>,
+}
+
+impl KeyedCount {
+    pub fn by_tag() -> Self {
+        Self {
+            heading: "Results by tag",
+            keys: |test_case| test_case.tags.clone(),
+            sorted: true,
+            counts: Vec::new(),
+        }
+    }
+
+    pub fn by_group() -> Self {
+        Self {
+            heading: "Results by group",
+            keys: |test_case| test_case.group.iter().cloned().collect(),
+            sorted: false,
+            counts: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, result: &TestResult) {
+        for key in (self.keys)(&result.test_case) {
+            match self.counts.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, count)) => count.add(&result.outcome),
+                None => {
+                    let mut count = ResultCount::new();
+                    count.add(&result.outcome);
+                    let index = match self.sorted {
+                        true => self.counts.partition_point(|(name, _)| *name < key),
+                        false => self.counts.len(),
+                    };
+                    self.counts.insert(index, (key, count));
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+}
+
+impl Display for KeyedCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.heading)?;
+        for (key, count) in &self.counts {
+            writeln!(f, "  {key}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::search_error::ErrorKind;
+    use crate::search_results::SearchResults;
+    use crate::test_fixtures::test_case;
+    use std::time::Duration;
+
+    fn result(tags: &[&str], group: Option<&str>, outcome: Outcome) -> TestResult {
+        TestResult {
+            test_case: TestCase {
+                tags: tags.iter().map(|tag| String::from(*tag)).collect(),
+                group: group.map(String::from),
+                ..test_case("description")
+            },
+            elapsed: Duration::from_millis(10),
+            outcome,
+            body: None,
+        }
+    }
+
+    fn not_found() -> Outcome {
+        Outcome::NotFound {
+            results: SearchResults::Suttaplex {
+                expected: SuttaplexUid::from("mn1"),
+                results: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn untagged_results_are_not_counted() {
+        let mut count = KeyedCount::by_tag();
+        count.add(&result(&[], Some("Partial"), Outcome::Success));
+        assert!(count.is_empty());
+    }
+
+    #[test]
+    fn ungrouped_results_are_not_counted() {
+        let mut count = KeyedCount::by_group();
+        count.add(&result(&["smoke"], None, Outcome::Success));
+        assert!(count.is_empty());
+    }
+
+    #[test]
+    fn display_counts_by_tag() {
+        let mut count = KeyedCount::by_tag();
+        count.add(&result(&["smoke", "pali"], None, Outcome::Success));
+        count.add(&result(
+            &["smoke"],
+            None,
+            Outcome::Error {
+                kind: ErrorKind::Transport,
+                message: String::from("Error sending HTTP request"),
+            },
+        ));
+
+        assert_eq!(
+            count.to_string(),
+            "Results by tag\n\
+            \x20 pali: 1 passed, 0 failed, 0 encountered an error\n\
+            \x20 smoke: 1 passed, 0 failed, 1 encountered an error (1 transport, 0 HTTP status, 0 parse)\n"
+        );
+    }
+
+    #[test]
+    fn display_counts_in_order_of_groups() {
+        let mut count = KeyedCount::by_group();
+        count.add(&result(&[], Some("Partial"), Outcome::Success));
+        count.add(&result(&[], Some("German"), Outcome::Success));
+        count.add(&result(&[], Some("Partial"), not_found()));
+
+        assert_eq!(
+            count.to_string(),
+            "Results by group\n\
+            \x20 Partial: 1 passed, 1 failed, 0 encountered an error\n\
+            \x20 German: 1 passed, 0 failed, 0 encountered an error\n"
+        );
+    }
+}
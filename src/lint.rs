@@ -0,0 +1,379 @@
+use crate::expected::Expected;
+use crate::identifiers::SearchResultKey;
+use crate::test_case::TestCase;
+use crate::test_suite::TestSuite;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
+
+const LANGUAGES: [&str; 44] = [
+    "ar", "bn", "bo", "ca", "cs", "de", "en", "es", "fa", "fi", "fr", "gu", "haw", "he", "hi",
+    "hr", "hu", "id", "it", "jpn", "kan", "ko", "lt", "lzh", "mr", "my", "nl", "no", "pgd", "pli",
+    "pl", "pra", "pt", "ro", "ru", "san", "si", "sk", "sl", "sr", "sv", "ta", "th", "vi",
+];
+
+static SUTTAPLEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9.-]*$").unwrap());
+static TEXT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^/[a-z0-9][a-z0-9./-]*$").unwrap());
+static DICTIONARY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^/define/[^\s/]+$").unwrap());
+static VOLPAGE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z]+ [A-Za-z]+( [ivxlcdm]+)? \d+$").unwrap());
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    Invalid {
+        message: String,
+    },
+    DuplicateDescription {
+        description: String,
+        count: usize,
+    },
+    DuplicateTest {
+        first: String,
+        second: String,
+    },
+    MalformedId {
+        description: String,
+        id: String,
+    },
+    MinRankAboveLimit {
+        description: String,
+        min_rank: usize,
+        limit: usize,
+    },
+    UnknownLanguage {
+        description: String,
+        language: String,
+    },
+}
+
+pub fn lint(test_suite: &TestSuite) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut test_cases = Vec::new();
+    for test_case in test_suite.test_cases() {
+        match test_case {
+            Ok(test_case) => test_cases.push(test_case),
+            Err(error) => problems.push(Problem::Invalid {
+                message: format!("{error:#}"),
+            }),
+        }
+    }
+    problems.extend(duplicate_descriptions(&test_cases));
+    problems.extend(duplicate_tests(&test_cases));
+    for test_case in &test_cases {
+        problems.extend(malformed_id(test_case));
+        problems.extend(min_rank_above_limit(test_case));
+        problems.extend(unknown_languages(test_case));
+    }
+    problems
+}
+
+fn duplicate_descriptions(test_cases: &[TestCase]) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    for (index, test_case) in test_cases.iter().enumerate() {
+        let earlier = test_cases[..index]
+            .iter()
+            .any(|earlier| earlier.description == test_case.description);
+        let count = test_cases
+            .iter()
+            .filter(|other| other.description == test_case.description)
+            .count();
+        if !earlier && count > 1 {
+            problems.push(Problem::DuplicateDescription {
+                description: test_case.description.clone(),
+                count,
+            });
+        }
+    }
+    problems
+}
+
+fn same_test(first: &TestCase, second: &TestCase) -> bool {
+    first.query == second.query
+        && first.limit == second.limit
+        && first.site_language == second.site_language
+        && first.restrict == second.restrict
+        && first.selected_languages == second.selected_languages
+        && first.match_partial == second.match_partial
+        && first.expected == second.expected
+}
+
+fn duplicate_tests(test_cases: &[TestCase]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for (index, test_case) in test_cases.iter().enumerate() {
+        if let Some(first) = test_cases[..index]
+            .iter()
+            .find(|earlier| same_test(earlier, test_case))
+        {
+            problems.push(Problem::DuplicateTest {
+                first: first.description.clone(),
+                second: test_case.description.clone(),
+            });
+        }
+    }
+    problems
+}
+
+fn malformed_id(test_case: &TestCase) -> Option<Problem> {
+    let (id, pattern) = match test_case.expected.as_ref()?.key() {
+        SearchResultKey::Suttaplex { uid } => (uid.to_string(), &SUTTAPLEX),
+        SearchResultKey::Text { url } => (url.to_string(), &TEXT),
+        SearchResultKey::Dictionary { url } => (url.to_string(), &DICTIONARY),
+        SearchResultKey::Volpage { reference } => (reference.to_string(), &VOLPAGE),
+    };
+    match pattern.is_match(id.as_str()) {
+        true => None,
+        false => Some(Problem::MalformedId {
+            description: test_case.description.clone(),
+            id,
+        }),
+    }
+}
+
+fn min_rank_above_limit(test_case: &TestCase) -> Option<Problem> {
+    match test_case.expected {
+        Some(Expected::Ranked { min_rank, .. }) if min_rank > test_case.limit => {
+            Some(Problem::MinRankAboveLimit {
+                description: test_case.description.clone(),
+                min_rank,
+                limit: test_case.limit,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn unknown_languages(test_case: &TestCase) -> Vec<Problem> {
+    let mut languages = vec![&test_case.site_language];
+    for language in &test_case.selected_languages {
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+    languages
+        .into_iter()
+        .filter(|language| !LANGUAGES.contains(&language.as_str()))
+        .map(|language| Problem::UnknownLanguage {
+            description: test_case.description.clone(),
+            language: language.clone(),
+        })
+        .collect()
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Invalid { message } => write!(f, "{message}"),
+            Problem::DuplicateDescription { description, count } => {
+                write!(f, "Description `{description}` is used by {count} tests")
+            }
+            Problem::DuplicateTest { first, second } => {
+                write!(
+                    f,
+                    "Tests `{first}` and `{second}` send the same request and expect the same result"
+                )
+            }
+            Problem::MalformedId { description, id } => {
+                write!(f, "Test case `{description}` expects malformed ID `{id}`")
+            }
+            Problem::MinRankAboveLimit {
+                description,
+                min_rank,
+                limit,
+            } => write!(
+                f,
+                "Test case `{description}` has min-rank {min_rank} greater than limit {limit}"
+            ),
+            Problem::UnknownLanguage {
+                description,
+                language,
+            } => write!(
+                f,
+                "Test case `{description}` uses unknown language `{language}`"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problems(test_cases: &str) -> Vec<String> {
+        let suite = TestSuite::load_from_string(
+            format!(
+                r#"
+                [settings]
+                endpoint = "http://localhost/api/search/instant"
+
+                [defaults]
+                limit = 10
+                site-language = "en"
+                restrict = "all"
+                selected-languages = ["en", "pli"]
+                match-partial = false
+
+                {test_cases}
+                "#
+            )
+            .as_str(),
+        )
+        .unwrap();
+        lint(&suite)
+            .iter()
+            .map(|problem| problem.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn no_problems() {
+        let problems = problems(
+            r#"
+            [[test-case]]
+            description = "Find the root of all things"
+            query = "mn1"
+            expected = { suttaplex = "mn1", min-rank = 1 }
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "snake"
+            expected.sutta = "/an5.77/en/sujato"
+
+            [[test-case]]
+            description = "Find a definition"
+            query = "metta"
+            expected.dictionary = "/define/metta"
+
+            [[test-case]]
+            description = "Find a volpage"
+            query = "SN ii 4"
+            expected.volpage = "PTS SN ii 4"
+            "#,
+        );
+        assert!(problems.is_empty(), "{problems:?}");
+    }
+
+    #[test]
+    fn duplicate_descriptions_and_tests() {
+        let problems = problems(
+            r#"
+            [[test-case]]
+            description = "Find metta"
+            query = "metta"
+
+            [[test-case]]
+            description = "Find metta"
+            query = "metta"
+            limit = 20
+
+            [[test-case]]
+            description = "Find metta again"
+            query = "metta"
+
+            [[test-case]]
+            description = "Find metta in the dictionary"
+            query = "metta"
+            expected.dictionary = "/define/metta"
+            "#,
+        );
+        assert_eq!(
+            problems,
+            vec![
+                "Description `Find metta` is used by 2 tests",
+                "Tests `Find metta` and `Find metta again` send the same request and expect the same result",
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_ids() {
+        let problems = problems(
+            r#"
+            [[test-case]]
+            description = "Suttaplex with a URL"
+            query = "mn1"
+            expected.suttaplex = "/mn1/en/sujato"
+
+            [[test-case]]
+            description = "Sutta without slash"
+            query = "snake"
+            expected.sutta = "an5.77/en/sujato"
+
+            [[test-case]]
+            description = "Dictionary without define"
+            query = "metta"
+            expected.dictionary = "metta"
+            "#,
+        );
+        assert_eq!(
+            problems,
+            vec![
+                "Test case `Suttaplex with a URL` expects malformed ID `/mn1/en/sujato`",
+                "Test case `Sutta without slash` expects malformed ID `an5.77/en/sujato`",
+                "Test case `Dictionary without define` expects malformed ID `metta`",
+            ]
+        );
+    }
+
+    #[test]
+    fn min_rank_greater_than_limit() {
+        let problems = problems(
+            r#"
+            [[test-case]]
+            description = "Rank can never be reached"
+            query = "mn1"
+            limit = 5
+            expected = { suttaplex = "mn1", min-rank = 6 }
+            "#,
+        );
+        assert_eq!(
+            problems,
+            vec!["Test case `Rank can never be reached` has min-rank 6 greater than limit 5"]
+        );
+    }
+
+    #[test]
+    fn unknown_languages() {
+        let problems = problems(
+            r#"
+            [[test-case]]
+            description = "Misspelt languages"
+            query = "metta"
+            site-language = "eng"
+            selected-languages = ["eng", "pi"]
+            "#,
+        );
+        assert_eq!(
+            problems,
+            vec![
+                "Test case `Misspelt languages` uses unknown language `eng`",
+                "Test case `Misspelt languages` uses unknown language `pi`",
+            ]
+        );
+    }
+
+    #[test]
+    fn all_invalid_test_cases_are_reported() {
+        let problems = problems(
+            r#"
+            [[test-case]]
+            description = "Two expectations"
+            query = "metta"
+            expected.sutta = "/snp1.8/en/sujato"
+            expected.dictionary = "/define/metta"
+
+            [[test-case]]
+            description = "Also two expectations"
+            query = "dosa"
+            expected.sutta = "/snp1.8/en/sujato"
+            expected.suttaplex = "snp1.8"
+            "#,
+        );
+        assert_eq!(
+            problems,
+            vec![
+                "suite.toml:13:13: Test case `Two expectations`: more than one expected result provided\n   |\n13 |             [[test-case]]\n   |             ^",
+                "suite.toml:19:13: Test case `Also two expectations`: more than one expected result provided\n   |\n19 |             [[test-case]]\n   |             ^",
+            ]
+        );
+    }
+}
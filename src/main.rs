@@ -1,55 +1,386 @@
+mod baseline;
+mod bless;
+mod category_results;
+mod cli;
+mod comparison;
+mod comparison_count;
 mod defaults;
+mod environment;
 mod expected;
 mod file_load;
+mod group;
+mod history;
+mod html_report;
 mod identifiers;
+mod include;
+mod json_report;
+mod junit;
+mod keyed_count;
+mod lint;
+mod markdown_report;
+mod markup;
+mod matrix;
+mod near_miss;
 mod outcome;
+mod overrides;
 mod rank;
 mod report;
+mod reporter;
 mod request;
 mod response;
 mod result_count;
 mod run;
+mod search_error;
 pub mod search_results;
 mod search_service;
+mod selection;
+mod source_location;
 mod summary;
+mod tap_report;
 mod test_case;
 #[cfg(test)]
+mod test_fixtures;
+#[cfg(test)]
 mod test_json;
+mod test_record;
 mod test_result;
 mod test_suite;
+mod test_table;
 mod timed_response;
+mod timing;
 
-use crate::file_load::load_suite;
+use crate::baseline::Baseline;
+use crate::bless::{bless_file, is_selected};
+use crate::cli::{Arguments, Command, Format};
+use crate::comparison_count::ComparisonCount;
+use crate::environment::Environment;
+use crate::file_load::{load_suite, suite_paths};
+use crate::history::{History, RunRecord};
+use crate::html_report::HtmlReport;
+use crate::json_report::JsonReporter;
+use crate::junit::JUnitReport;
+use crate::keyed_count::KeyedCount;
+use crate::lint::lint;
+use crate::markdown_report::MarkdownReporter;
+use crate::reporter::{Reporter, TextReporter, Verbosity};
 use crate::result_count::ResultCount;
-use crate::run::Runner;
-use crate::summary::Summary;
-use anyhow::Result;
+use crate::run::{ComparisonRunner, Runner};
+use crate::source_location::combine;
+use crate::tap_report::TapReporter;
+use crate::test_case::TestCase;
+use crate::test_record::TestRecord;
+use crate::test_suite::TestSuite;
+use crate::timing::Timings;
+use anyhow::{Result, anyhow};
+use clap::Parser;
 use search_service::LiveSearchService;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+fn suite_name(suite_path: &Path) -> String {
+    suite_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn selected_message(selected: usize, total: usize) -> String {
+    format!(
+        "Selected {selected} of {total} tests, skipped {}",
+        total - selected
+    )
+}
+
+fn reporter(arguments: &Arguments) -> Box<dyn Reporter> {
+    match arguments.format {
+        Format::Text => Box::new(TextReporter::new(
+            std::io::stdout(),
+            arguments.verbosity(),
+            arguments.show_top,
+            std::io::stdout().is_terminal(),
+        )),
+        Format::Json => Box::new(JsonReporter::new(std::io::stdout())),
+        Format::Tap => Box::new(TapReporter::new(std::io::stdout())),
+        Format::Markdown => Box::new(MarkdownReporter::new(std::io::stdout())),
+    }
+}
 
-fn run_application() -> Result<ResultCount> {
-    let test_suite = load_suite()?;
-    let search_service = LiveSearchService::new(test_suite.endpoint().clone());
-    let runner = Runner::new(&test_suite, search_service)?;
+fn run_tests(
+    suite_path: &Path,
+    test_suite: &TestSuite,
+    test_cases: Vec<TestCase>,
+    selected: Option<String>,
+    arguments: &Arguments,
+) -> Result<(ResultCount, bool)> {
+    let search_service = LiveSearchService::new(test_suite.environment());
+    let runner = Runner::new(test_cases, search_service);
+    let mut reporter = reporter(arguments);
 
-    println!("{}", test_suite.headline());
-    println!();
+    reporter.begin(suite_path, test_suite, runner.test_count())?;
+    let start = Instant::now();
 
     let mut result_count = ResultCount::new();
+    let mut group_count = KeyedCount::by_group();
+    let mut tag_count = KeyedCount::by_tag();
+    let mut records = Vec::new();
+    let mut to_bless = Vec::new();
+    let mut junit = arguments
+        .junit
+        .as_ref()
+        .map(|_| JUnitReport::new(suite_name(suite_path), test_suite.endpoint()));
+    let mut html = arguments
+        .html
+        .as_ref()
+        .map(|_| HtmlReport::new(suite_name(suite_path), test_suite.endpoint()));
 
     for result in runner.run() {
-        print!("{result}");
+        reporter.result(&result)?;
+        sleep(Duration::from_millis(test_suite.delay()));
+        result_count.add(&result.outcome);
+        group_count.add(&result);
+        tag_count.add(&result);
+        records.push(TestRecord::from(&result));
+        if let Some(junit) = &mut junit {
+            junit.add(&result);
+        }
+        if let Some(html) = &mut html {
+            html.add(&result);
+        }
+        if is_selected(result.test_case.description.as_str(), &arguments.bless) {
+            to_bless.push(result);
+        }
+    }
+
+    if let Some(selected) = selected {
+        reporter.note(selected.as_str())?;
+    }
+
+    if !group_count.is_empty() {
+        reporter.info(group_count.to_string().as_str())?;
+    }
+
+    if !tag_count.is_empty() {
+        reporter.info(tag_count.to_string().as_str())?;
+    }
+
+    if !records.is_empty() {
+        let timings = Timings::new(start.elapsed(), &records);
+        reporter.info(timings.to_string().as_str())?;
+    }
+
+    let mut passed = result_count.all_passed();
+    if let Some(path) = &arguments.baseline {
+        let regressions = Baseline::load(path)?.regressions(&records, arguments.latency_threshold);
+        reporter.note(regressions.to_string().as_str())?;
+        passed = regressions.passed();
+    }
+
+    if let (Some(junit), Some(path)) = (&junit, &arguments.junit) {
+        junit.save(path)?;
+    }
+
+    if let (Some(html), Some(path)) = (&html, &arguments.html) {
+        html.save(path)?;
+    }
+
+    if !arguments.bless.is_empty() {
+        let blessing = bless_file(suite_path, &to_bless)?;
+        reporter.note(blessing.to_string().as_str())?;
+    }
+
+    if arguments.history {
+        let run = RunRecord::new(suite_path, test_suite.endpoint(), records.clone())?;
+        History::append(suite_path, &run)?;
+    }
+
+    if let Some(path) = &arguments.save_baseline {
+        Baseline::new(records).save(path)?;
+    }
+
+    reporter.finish(&result_count)?;
+    Ok((result_count, passed))
+}
+
+fn run_comparison(
+    test_suite: &TestSuite,
+    test_cases: Vec<TestCase>,
+    compare_endpoint: String,
+    selected: Option<String>,
+    verbosity: Verbosity,
+) -> Result<ResultCount> {
+    let primary = LiveSearchService::new(test_suite.environment());
+    let secondary = LiveSearchService::new(Environment {
+        endpoint: compare_endpoint.clone(),
+        ..test_suite.environment()
+    });
+    let runner = ComparisonRunner::new(test_cases, primary, secondary);
+
+    let quiet = verbosity == Verbosity::Quiet;
+    if !quiet {
+        println!("{}", test_suite.headline());
+        println!();
+    }
+
+    let mut primary_count = ResultCount::new();
+    let mut secondary_count = ResultCount::new();
+    let mut comparison_count = ComparisonCount::new();
+
+    for comparison in runner.run() {
+        if !quiet || comparison.differs() {
+            print!("{comparison}");
+        }
         sleep(Duration::from_millis(test_suite.delay()));
-        result_count.add(&Summary::from(&result.outcome));
+        primary_count.add(&comparison.primary.outcome);
+        secondary_count.add(&comparison.secondary.outcome);
+        comparison_count.add(&comparison);
+    }
+
+    println!("{}: {primary_count}", test_suite.endpoint());
+    println!("{compare_endpoint}: {secondary_count}");
+    println!("{comparison_count}");
+    if let Some(selected) = selected {
+        println!("{selected}");
+    }
+    Ok(primary_count)
+}
+
+fn run_application(arguments: &Arguments) -> Result<bool> {
+    match &arguments.command {
+        Some(Command::History { suite }) => {
+            print!("{}", History::load(suite)?);
+            Ok(true)
+        }
+        Some(Command::Lint { suites }) => lint_suites(suites),
+        None => run_suites(arguments),
     }
+}
+
+fn lint_suites(suites: &[PathBuf]) -> Result<bool> {
+    let mut total = 0;
+    for suite_path in suite_paths(suites)? {
+        let problems: Vec<String> = match load_suite(&suite_path, None, &[]) {
+            Ok(test_suite) => lint(&test_suite)
+                .iter()
+                .map(|problem| problem.to_string())
+                .collect(),
+            Err(error) => vec![format!("{error:#}")],
+        };
+        if !problems.is_empty() {
+            println!("{}", suite_path.display());
+            for problem in &problems {
+                for line in problem.lines() {
+                    match line.is_empty() {
+                        true => println!(),
+                        false => println!("  {line}"),
+                    }
+                }
+            }
+            println!();
+        }
+        total += problems.len();
+    }
+    match total {
+        0 => println!("No problems found"),
+        1 => println!("1 problem found"),
+        total => println!("{total} problems found"),
+    }
+    Ok(total == 0)
+}
+
+fn run_suites(arguments: &Arguments) -> Result<bool> {
+    let suite_paths = suite_paths(&arguments.suites)?;
+    let several = suite_paths.len() > 1;
+    if several && arguments.has_single_suite_options() {
+        return Err(anyhow!(
+            "Baselines, JUnit and HTML reports and TAP output can only be used with a single suite"
+        ));
+    }
+    let selection = arguments.selection()?;
+    let mut suites = Vec::new();
+    let mut errors = Vec::new();
+    for path in &suite_paths {
+        let loaded = load_suite(path, arguments.env.as_deref(), &arguments.set)
+            .and_then(|test_suite| Ok((selection.test_cases(&test_suite)?, test_suite)));
+        match loaded {
+            Ok(suite) => suites.push(suite),
+            Err(error) => errors.push(error),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(combine(errors));
+    }
+    let headings = several && arguments.format == Format::Text;
 
-    Ok(result_count)
+    let mut total = ResultCount::new();
+    let mut all_passed = true;
+    for (suite_path, (test_cases, test_suite)) in suite_paths.iter().zip(suites) {
+        if headings {
+            println!("Suite {}", suite_path.display());
+            println!();
+        }
+        let (count, passed) = run_suite(
+            suite_path,
+            &test_suite,
+            test_cases,
+            selection.is_filtering(),
+            arguments,
+        )?;
+        total.add_count(&count);
+        all_passed &= passed;
+        if headings {
+            println!();
+        }
+    }
+    if several {
+        reporter(arguments).total(&total)?;
+    }
+    Ok(all_passed)
+}
+
+fn run_suite(
+    suite_path: &Path,
+    test_suite: &TestSuite,
+    test_cases: Vec<TestCase>,
+    filtering: bool,
+    arguments: &Arguments,
+) -> Result<(ResultCount, bool)> {
+    let selected = match filtering {
+        true => Some(selected_message(test_cases.len(), test_suite.test_count())),
+        false => None,
+    };
+    match test_suite.compare_endpoint() {
+        Some(_) if arguments.has_single_endpoint_options() => Err(anyhow!(
+            "Baselines, history, bless and reports cannot be used when comparing two endpoints"
+        )),
+        Some(_) if arguments.format != Format::Text => Err(anyhow!(
+            "Only text output can be used when comparing two endpoints"
+        )),
+        Some(compare_endpoint) => {
+            let count = run_comparison(
+                test_suite,
+                test_cases,
+                compare_endpoint,
+                selected,
+                arguments.verbosity(),
+            )?;
+            let passed = count.all_passed();
+            Ok((count, passed))
+        }
+        None => run_tests(suite_path, test_suite, test_cases, selected, arguments),
+    }
 }
 
-fn main() {
-    match run_application() {
-        Ok(count) => println!("{count}"),
-        Err(error) => println!("{error:#}"),
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+    match run_application(&arguments) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            println!("{error:#}");
+            ExitCode::FAILURE
+        }
     }
 }
@@ -0,0 +1,238 @@
+use crate::reporter::Reporter;
+use crate::result_count::ResultCount;
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use crate::test_suite::TestSuite;
+use anyhow::Result;
+use std::io::Write;
+use std::path::Path;
+
+pub struct MarkdownReporter<W: Write> {
+    writer: W,
+    name: String,
+    headline: String,
+    problems: Vec<TestResult>,
+    passes: Vec<TestResult>,
+    notes: Vec<String>,
+}
+
+impl<W: Write> MarkdownReporter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            name: String::new(),
+            headline: String::new(),
+            problems: Vec::new(),
+            passes: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    fn cell(text: &str) -> String {
+        text.replace('|', "\\|").replace('\n', " ")
+    }
+
+    fn row(result: &TestResult, with_detail: bool) -> String {
+        let summary = Summary::from(&result.outcome);
+        let elapsed = format!("{}ms", result.elapsed.as_millis());
+        let description = Self::cell(result.test_case.description.as_str());
+        match with_detail {
+            true => format!(
+                "| {summary} | {elapsed} | {description} | {} |",
+                Self::cell(result.detail_line().unwrap_or_default().as_str())
+            ),
+            false => format!("| {summary} | {elapsed} | {description} |"),
+        }
+    }
+}
+
+impl<W: Write> Reporter for MarkdownReporter<W> {
+    fn begin(&mut self, suite_path: &Path, test_suite: &TestSuite, _total: usize) -> Result<()> {
+        self.name = suite_path.display().to_string();
+        self.headline = test_suite.headline();
+        Ok(())
+    }
+
+    fn result(&mut self, result: &TestResult) -> Result<()> {
+        match Summary::from(&result.outcome) {
+            Summary::Passed => self.passes.push(result.clone()),
+            Summary::Failed | Summary::Error => self.problems.push(result.clone()),
+        }
+        Ok(())
+    }
+
+    fn note(&mut self, note: &str) -> Result<()> {
+        self.notes.push(String::from(note.trim_end()));
+        Ok(())
+    }
+
+    fn info(&mut self, info: &str) -> Result<()> {
+        self.note(info)
+    }
+
+    fn finish(&mut self, count: &ResultCount) -> Result<()> {
+        writeln!(self.writer, "### Search test results for `{}`", self.name)?;
+        writeln!(self.writer)?;
+        writeln!(self.writer, "{}", self.headline)?;
+        writeln!(self.writer)?;
+        writeln!(self.writer, "**{count}**")?;
+
+        if !self.problems.is_empty() {
+            writeln!(self.writer)?;
+            writeln!(self.writer, "| Outcome | Time | Description | Detail |")?;
+            writeln!(self.writer, "| --- | ---: | --- | --- |")?;
+            for result in &self.problems {
+                writeln!(self.writer, "{}", Self::row(result, true))?;
+            }
+        }
+
+        if !self.passes.is_empty() {
+            writeln!(self.writer)?;
+            writeln!(self.writer, "<details>")?;
+            writeln!(
+                self.writer,
+                "<summary>{} passed</summary>",
+                self.passes.len()
+            )?;
+            writeln!(self.writer)?;
+            writeln!(self.writer, "| Outcome | Time | Description |")?;
+            writeln!(self.writer, "| --- | ---: | --- |")?;
+            for result in &self.passes {
+                writeln!(self.writer, "{}", Self::row(result, false))?;
+            }
+            writeln!(self.writer)?;
+            writeln!(self.writer, "</details>")?;
+        }
+
+        for note in &self.notes {
+            writeln!(self.writer)?;
+            writeln!(self.writer, "```")?;
+            writeln!(self.writer, "{note}")?;
+            writeln!(self.writer, "```")?;
+        }
+        Ok(())
+    }
+
+    fn total(&mut self, count: &ResultCount) -> Result<()> {
+        writeln!(self.writer)?;
+        writeln!(self.writer, "### All suites")?;
+        writeln!(self.writer)?;
+        writeln!(self.writer, "**{count}**")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::search_results::SearchResults;
+    use crate::test_fixtures::test_case;
+    use std::time::Duration;
+
+    fn suite() -> TestSuite {
+        TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Search is successful"
+            query = "dhamma"
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn render(results: &[TestResult], notes: &[&str]) -> String {
+        let mut count = ResultCount::new();
+        let mut output = Vec::new();
+        let mut reporter = MarkdownReporter::new(&mut output);
+        reporter
+            .begin(Path::new("suite.toml"), &suite(), results.len())
+            .unwrap();
+        for result in results {
+            count.add(&result.outcome);
+            reporter.result(result).unwrap();
+        }
+        for note in notes {
+            reporter.note(note).unwrap();
+        }
+        reporter.finish(&count).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn failures_in_table_and_passes_collapsed() {
+        let results = vec![
+            TestResult {
+                test_case: test_case("Search is successful"),
+                elapsed: Duration::from_millis(679),
+                outcome: Outcome::Success,
+                body: None,
+            },
+            TestResult {
+                test_case: test_case("Find mn1 | mn2"),
+                elapsed: Duration::from_millis(21),
+                outcome: Outcome::NotFound {
+                    results: SearchResults::Suttaplex {
+                        expected: SuttaplexUid::from("mn1"),
+                        results: vec![],
+                    },
+                },
+                body: None,
+            },
+        ];
+
+        assert_eq!(
+            render(&results, &["Compared with baseline\n"]),
+            "### Search test results for `suite.toml`\n\
+            \n\
+            Running tests against endpoint http://localhost/api/search/instant with 0ms delay\n\
+            \n\
+            **1 passed, 1 failed, 0 encountered an error**\n\
+            \n\
+            | Outcome | Time | Description | Detail |\n\
+            | --- | ---: | --- | --- |\n\
+            | FAILED | 21ms | Find mn1 \\| mn2 | Suttaplex hit mn1 not found in search results |\n\
+            \n\
+            <details>\n\
+            <summary>1 passed</summary>\n\
+            \n\
+            | Outcome | Time | Description |\n\
+            | --- | ---: | --- |\n\
+            | PASSED | 679ms | Search is successful |\n\
+            \n\
+            </details>\n\
+            \n\
+            ```\n\
+            Compared with baseline\n\
+            ```\n"
+        );
+    }
+
+    #[test]
+    fn no_results() {
+        assert_eq!(
+            render(&[], &[]),
+            "### Search test results for `suite.toml`\n\
+            \n\
+            Running tests against endpoint http://localhost/api/search/instant with 0ms delay\n\
+            \n\
+            **0 passed, 0 failed, 0 encountered an error**\n"
+        );
+    }
+
+    #[test]
+    fn total_for_all_suites() {
+        let mut output = Vec::new();
+        let mut count = ResultCount::new();
+        count.add(&Outcome::Success);
+        MarkdownReporter::new(&mut output).total(&count).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\n### All suites\n\n**1 passed, 0 failed, 0 encountered an error**\n"
+        );
+    }
+}
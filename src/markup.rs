@@ -0,0 +1,33 @@
+pub fn escape(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '&' => String::from("&amp;"),
+            '<' => String::from("&lt;"),
+            '>' => String::from("&gt;"),
+            '"' => String::from("&quot;"),
+            '\'' => String::from("&apos;"),
+            _ => character.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_is_unchanged() {
+        assert_eq!(
+            escape("Metta sutta /snp5.1/en/sujato"),
+            "Metta sutta /snp5.1/en/sujato"
+        );
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        assert_eq!(
+            escape(r#"by:sujato+"the Bamboo Grove" <a> & 'b'"#),
+            "by:sujato+&quot;the Bamboo Grove&quot; &lt;a&gt; &amp; &apos;b&apos;"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::defaults::Defaults;
+use crate::test_case::Origin;
+use crate::test_suite::TestCaseDetails;
+use anyhow::{Context, Result, anyhow};
+use std::collections::BTreeMap;
+use toml::{Table, Value};
+
+pub fn expand(details: &TestCaseDetails) -> Result<Vec<TestCaseDetails>> {
+    if details.matrix.is_empty() {
+        return Ok(vec![details.clone()]);
+    }
+    combinations(&details.matrix)
+        .with_context(|| format!("Test case `{}`", details.description))?
+        .into_iter()
+        .map(|combination| {
+            apply(details, combination)
+                .with_context(|| format!("Test case `{}`", details.description))
+        })
+        .collect()
+}
+
+fn combinations(matrix: &BTreeMap<String, Vec<Value>>) -> Result<Vec<Table>> {
+    let mut combinations = vec![Table::new()];
+    for (key, values) in matrix {
+        if values.is_empty() {
+            return Err(anyhow!("matrix has no values for `{key}`"));
+        }
+        combinations = combinations
+            .iter()
+            .flat_map(|combination| {
+                values.iter().map(|value| {
+                    let mut combination = combination.clone();
+                    combination.insert(key.clone(), value.clone());
+                    combination
+                })
+            })
+            .collect();
+    }
+    Ok(combinations)
+}
+
+fn apply(details: &TestCaseDetails, combination: Table) -> Result<TestCaseDetails> {
+    let description = format!("{} [{}]", details.description, label(&combination));
+    let parameters: Defaults = Value::Table(combination)
+        .try_into()
+        .context("invalid matrix")?;
+    let mut tags = details.tags.clone();
+    tags.extend(parameters.tags);
+    Ok(TestCaseDetails {
+        description,
+        limit: parameters.limit.or(details.limit),
+        site_language: parameters.site_language.or(details.site_language.clone()),
+        restrict: parameters.restrict.or(details.restrict.clone()),
+        selected_languages: parameters
+            .selected_languages
+            .or(details.selected_languages.clone()),
+        match_partial: parameters.match_partial.or(details.match_partial),
+        tags,
+        matrix: BTreeMap::new(),
+        origin: Origin::Matrix,
+        ..details.clone()
+    })
+}
+
+fn label(combination: &Table) -> String {
+    combination
+        .iter()
+        .map(|(key, value)| match value {
+            Value::String(text) => format!("{key}={text}"),
+            value => format!("{key}={value}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn details(matrix: &str) -> TestCaseDetails {
+        let mut details: TestCaseDetails = toml::from_str(
+            format!(
+                r#"
+                description = "Find the root of all things"
+                query = "mn1"
+                site-language = "en"
+                {matrix}
+                "#
+            )
+            .as_str(),
+        )
+        .unwrap();
+        details.limit = Some(10);
+        details
+    }
+
+    #[test]
+    fn without_matrix_is_unchanged() {
+        let details = details("");
+        assert_eq!(expand(&details).unwrap(), vec![details]);
+    }
+
+    #[test]
+    fn expanded_cases_come_from_matrix() {
+        let expanded = expand(&details(r#"matrix = { limit = [1, 2] }"#)).unwrap();
+        assert!(
+            expanded
+                .iter()
+                .all(|details| details.origin == Origin::Matrix)
+        );
+    }
+
+    #[test]
+    fn expands_cartesian_product() {
+        let expanded = expand(&details(
+            r#"matrix = { site-language = ["en", "de"], match-partial = [false, true] }"#,
+        ))
+        .unwrap();
+
+        let summary: Vec<(&str, Option<&str>, Option<bool>)> = expanded
+            .iter()
+            .map(|details| {
+                (
+                    details.description.as_str(),
+                    details.site_language.as_deref(),
+                    details.match_partial,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "Find the root of all things [match-partial=false, site-language=en]",
+                    Some("en"),
+                    Some(false)
+                ),
+                (
+                    "Find the root of all things [match-partial=false, site-language=de]",
+                    Some("de"),
+                    Some(false)
+                ),
+                (
+                    "Find the root of all things [match-partial=true, site-language=en]",
+                    Some("en"),
+                    Some(true)
+                ),
+                (
+                    "Find the root of all things [match-partial=true, site-language=de]",
+                    Some("de"),
+                    Some(true)
+                ),
+            ]
+        );
+        assert!(expanded.iter().all(|details| details.matrix.is_empty()));
+        assert!(expanded.iter().all(|details| details.limit == Some(10)));
+    }
+
+    #[test]
+    fn array_values_are_labelled() {
+        let expanded = expand(&details(
+            r#"matrix = { selected-languages = [["en"], ["de", "pli"]] }"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            expanded[1].description,
+            r#"Find the root of all things [selected-languages=["de", "pli"]]"#
+        );
+        assert_eq!(
+            expanded[1].selected_languages,
+            Some(vec![String::from("de"), String::from("pli")])
+        );
+    }
+
+    #[test]
+    fn error_when_key_unknown() {
+        let error = expand(&details(r#"matrix = { query = ["mn1", "mn2"] }"#)).unwrap_err();
+        assert!(
+            format!("{error:#}")
+                .starts_with("Test case `Find the root of all things`: invalid matrix: ")
+        );
+    }
+
+    #[test]
+    fn error_when_no_values() {
+        let error = expand(&details(r#"matrix = { site-language = [] }"#)).unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "Test case `Find the root of all things`: matrix has no values for `site-language`"
+        );
+    }
+}
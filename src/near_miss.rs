@@ -0,0 +1,173 @@
+use deunicode::deunicode;
+use std::fmt::{Display, Formatter};
+use strsim::levenshtein;
+
+const MAXIMUM_DISTANCE: usize = 2;
+const CHARACTERS_PER_EDIT: usize = 5;
+const MAXIMUM_SUGGESTIONS: usize = 3;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearMiss {
+    pub id: String,
+    pub rank: usize,
+}
+
+impl NearMiss {
+    pub fn find(expected: &str, actual: &[String]) -> Vec<NearMiss> {
+        let mut scored: Vec<(usize, NearMiss)> = actual
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| id.as_str() != expected)
+            .filter_map(|(position, id)| {
+                Self::score(expected, id).map(|score| {
+                    let near_miss = NearMiss {
+                        id: id.clone(),
+                        rank: position + 1,
+                    };
+                    (score, near_miss)
+                })
+            })
+            .collect();
+        scored.sort_by_key(|(score, near_miss)| (*score, near_miss.rank));
+        scored.dedup_by(|a, b| a.1.id == b.1.id);
+        scored
+            .into_iter()
+            .take(MAXIMUM_SUGGESTIONS)
+            .map(|(_, near_miss)| near_miss)
+            .collect()
+    }
+
+    fn score(expected: &str, actual: &str) -> Option<usize> {
+        let expected = normalise(expected);
+        let actual = normalise(actual);
+        if expected == actual {
+            return Some(0);
+        }
+        if let (Some(expected_uid), Some(actual_uid)) = (text_uid(&expected), text_uid(&actual))
+            && expected_uid == actual_uid
+        {
+            return Some(1);
+        }
+        let allowed = (expected.chars().count() / CHARACTERS_PER_EDIT).min(MAXIMUM_DISTANCE);
+        match levenshtein(expected.as_str(), actual.as_str()) {
+            distance if distance <= allowed => Some(1 + distance),
+            _ => None,
+        }
+    }
+}
+
+fn normalise(id: &str) -> String {
+    deunicode(id).to_lowercase()
+}
+
+fn text_uid(url: &str) -> Option<&str> {
+    let mut segments = url.strip_prefix('/')?.split('/');
+    let uid = segments.next()?;
+    let _language = segments.next()?;
+    let _author = segments.next()?;
+    match segments.next() {
+        None => Some(uid),
+        Some(_) => None,
+    }
+}
+
+impl Display for NearMiss {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at rank {}", self.id, self.rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| String::from(*id)).collect()
+    }
+
+    fn near_miss(id: &str, rank: usize) -> NearMiss {
+        NearMiss {
+            id: String::from(id),
+            rank,
+        }
+    }
+
+    #[test]
+    fn different_author_is_near_miss() {
+        let actual = ids(&["/mn2/en/sujato", "/mn1/en/sujato"]);
+        assert_eq!(
+            NearMiss::find("/mn1/en/bodhi", &actual),
+            vec![near_miss("/mn1/en/sujato", 2)]
+        );
+    }
+
+    #[test]
+    fn different_diacritics_is_near_miss() {
+        let actual = ids(&["/define/dosa", "/define/mettā"]);
+        assert_eq!(
+            NearMiss::find("/define/metta", &actual),
+            vec![near_miss("/define/mettā", 2)]
+        );
+    }
+
+    #[test]
+    fn typo_is_near_miss() {
+        let actual = ids(&["/define/dosa", "/define/meta"]);
+        assert_eq!(
+            NearMiss::find("/define/metta", &actual),
+            vec![near_miss("/define/meta", 2)]
+        );
+    }
+
+    #[test]
+    fn short_ids_need_closer_match() {
+        let actual = ids(&["mn2", "MN1", "an11.15"]);
+        assert_eq!(NearMiss::find("mn1", &actual), vec![near_miss("MN1", 2)]);
+    }
+
+    #[test]
+    fn closest_are_suggested_first() {
+        let actual = ids(&["/snp1.8/en/sujato", "/snp5.2/en/sujato", "/snp5.1/en/mills"]);
+        assert_eq!(
+            NearMiss::find("/snp5.1/en/sujato", &actual),
+            vec![
+                near_miss("/snp5.1/en/mills", 3),
+                near_miss("/snp5.2/en/sujato", 2),
+                near_miss("/snp1.8/en/sujato", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn suggestions_are_limited() {
+        let actual = ids(&[
+            "/mn2/en/a",
+            "/mn1/en/b",
+            "/mn1/en/c",
+            "/mn1/en/d",
+            "/mn1/en/e",
+        ]);
+        assert_eq!(
+            NearMiss::find("/mn1/en/sujato", &actual),
+            vec![
+                near_miss("/mn1/en/b", 2),
+                near_miss("/mn1/en/c", 3),
+                near_miss("/mn1/en/d", 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn unrelated_ids_are_not_near_misses() {
+        let actual = ids(&["/an11.15/en/sujato", "/sn56.11/en/sujato"]);
+        assert!(NearMiss::find("/mn1/en/bodhi", &actual).is_empty());
+    }
+
+    #[test]
+    fn display_near_miss() {
+        assert_eq!(
+            near_miss("/mn1/en/sujato", 2).to_string(),
+            "/mn1/en/sujato at rank 2"
+        );
+    }
+}
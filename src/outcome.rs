@@ -1,11 +1,12 @@
 use crate::expected::Expected;
 use crate::rank::Rank;
+use crate::search_error::{ErrorKind, SearchError};
 use crate::search_results::SearchResults;
 use anyhow::{Context, Result};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Outcome {
-    Error { message: String },
+    Error { kind: ErrorKind, message: String },
     Success,
     Found { results: SearchResults },
     NotFound { results: SearchResults },
@@ -15,6 +16,7 @@ pub enum Outcome {
 impl Outcome {
     pub fn new(expected: &Option<Expected>, maybe_json: Result<String>) -> Self {
         Self::outcome_or_error(expected, maybe_json).unwrap_or_else(|error| Self::Error {
+            kind: ErrorKind::of(&error),
             message: format!("{error:#}"),
         })
     }
@@ -27,13 +29,47 @@ impl Outcome {
         match expected {
             None => Ok(Self::Success),
             Some(expected) => {
-                let results = SearchResults::new(&expected.key(), json.as_str())
-                    .context("Could not extract search results from server response")?;
+                let results = SearchResults::new(&expected.key(), json.as_str()).context(
+                    SearchError::new(
+                        ErrorKind::Parse,
+                        "Could not extract search results from server response",
+                    ),
+                )?;
                 Ok(Self::with_expected(expected, &results))
             }
         }
     }
 
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Outcome::Error { .. } => "error",
+            Outcome::Success => "success",
+            Outcome::Found { results: _ } => "found",
+            Outcome::NotFound { results: _ } => "not-found",
+            Outcome::Ranked {
+                results: _,
+                rank: _,
+            } => "ranked",
+        }
+    }
+
+    pub fn results(&self) -> Option<&SearchResults> {
+        match self {
+            Outcome::Error { .. } => None,
+            Outcome::Success => None,
+            Outcome::Found { results } => Some(results),
+            Outcome::NotFound { results } => Some(results),
+            Outcome::Ranked { results, rank: _ } => Some(results),
+        }
+    }
+
+    pub fn rank(&self) -> Option<&Rank> {
+        match self {
+            Outcome::Ranked { results: _, rank } => Some(rank),
+            _ => None,
+        }
+    }
+
     fn with_expected(expected: &Expected, results: &SearchResults) -> Self {
         match expected {
             Expected::Unranked { key: _ } => match results.found() {
@@ -69,6 +105,7 @@ mod tests {
         assert_eq!(
             Outcome::new(&None, Err(anyhow!(BAD_RESPONSE_MESSAGE))),
             Outcome::Error {
+                kind: ErrorKind::Transport,
                 message: String::from(BAD_RESPONSE_MESSAGE)
             }
         )
@@ -85,11 +122,28 @@ mod tests {
         assert_eq!(
             Outcome::new(&expected, Ok(String::from(BAD_JSON))),
             Outcome::Error {
+                kind: ErrorKind::Parse,
                 message: String::from(BAD_JSON_MESSAGE)
             }
         )
     }
 
+    #[test]
+    fn error_kind_is_carried_from_response() {
+        let error = anyhow!(SearchError::new(
+            ErrorKind::HttpStatus,
+            "Expected status code to be 200 OK but got 502 Bad Gateway"
+        ));
+
+        assert_eq!(
+            Outcome::new(&None, Err(error)),
+            Outcome::Error {
+                kind: ErrorKind::HttpStatus,
+                message: String::from("Expected status code to be 200 OK but got 502 Bad Gateway")
+            }
+        )
+    }
+
     #[test]
     fn success_when_nothing_expected_and_json_parses() {
         assert_eq!(
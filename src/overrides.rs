@@ -0,0 +1,279 @@
+use anyhow::{Context, Result};
+use std::str::FromStr;
+use toml_edit::{DocumentMut, Item, Table, TableLike, Value};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Override {
+    key: Vec<String>,
+    value: String,
+}
+
+impl Override {
+    fn key(&self) -> String {
+        self.key.join(".")
+    }
+
+    pub fn sets(&self, key: &str) -> bool {
+        self.key() == key
+    }
+
+    fn value(&self) -> Value {
+        let mut value = self
+            .value
+            .parse::<Value>()
+            .unwrap_or_else(|_| Value::from(self.value.as_str()));
+        value.decor_mut().clear();
+        value
+    }
+
+    fn apply(&self, document: &mut DocumentMut) -> Result<()> {
+        let Some((last, parents)) = self.key.split_last() else {
+            return Ok(());
+        };
+        let mut table: &mut dyn TableLike = document.as_table_mut();
+        for part in parents {
+            table = table
+                .entry(part)
+                .or_insert(toml_edit::table())
+                .as_table_like_mut()
+                .with_context(|| format!("Cannot set `{}`: `{part}` is not a table", self.key()))?;
+        }
+        table.insert(last, Item::Value(self.value()));
+        Ok(())
+    }
+}
+
+impl FromStr for Override {
+    type Err = String;
+
+    fn from_str(setting: &str) -> Result<Self, Self::Err> {
+        let (key, value) = setting
+            .split_once('=')
+            .ok_or(format!("expected KEY=VALUE but found `{setting}`"))?;
+        let key: Vec<String> = key.split('.').map(|part| part.trim().to_string()).collect();
+        if key.iter().any(String::is_empty) {
+            return Err(format!("invalid key in `{setting}`"));
+        }
+        Ok(Self {
+            key,
+            value: value.to_string(),
+        })
+    }
+}
+
+pub fn apply_overrides(
+    source: &str,
+    overrides: &[Override],
+    variables: impl Fn(&str) -> Option<String>,
+) -> Result<String> {
+    let mut document = source
+        .parse::<DocumentMut>()
+        .context("Failed to parse TOML.")?;
+    interpolate_table(document.as_table_mut(), &variables)?;
+    for setting in overrides {
+        setting.apply(&mut document)?;
+    }
+    Ok(document.to_string())
+}
+
+fn interpolate_table(table: &mut Table, variables: &dyn Fn(&str) -> Option<String>) -> Result<()> {
+    for (_, item) in table.iter_mut() {
+        interpolate_item(item, variables)?;
+    }
+    Ok(())
+}
+
+fn interpolate_item(item: &mut Item, variables: &dyn Fn(&str) -> Option<String>) -> Result<()> {
+    match item {
+        Item::Value(value) => interpolate_value(value, variables),
+        Item::Table(table) => interpolate_table(table, variables),
+        Item::ArrayOfTables(tables) => {
+            for table in tables.iter_mut() {
+                interpolate_table(table, variables)?;
+            }
+            Ok(())
+        }
+        Item::None => Ok(()),
+    }
+}
+
+fn interpolate_value(value: &mut Value, variables: &dyn Fn(&str) -> Option<String>) -> Result<()> {
+    match value {
+        Value::String(text) if text.value().contains("${") => {
+            let decor = text.decor().clone();
+            *value = Value::from(interpolate(text.value(), variables)?);
+            *value.decor_mut() = decor;
+        }
+        Value::Array(array) => {
+            for element in array.iter_mut() {
+                interpolate_value(element, variables)?;
+            }
+        }
+        Value::InlineTable(table) => {
+            for (_, element) in table.iter_mut() {
+                interpolate_value(element, variables)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn interpolate(text: &str, variables: &dyn Fn(&str) -> Option<String>) -> Result<String> {
+    let mut interpolated = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        interpolated.push_str(&rest[..start]);
+        let length = rest[start..]
+            .find('}')
+            .with_context(|| format!("Unclosed `${{` in `{text}`"))?;
+        let name = &rest[start + 2..start + length];
+        let value =
+            variables(name).with_context(|| format!("Environment variable `{name}` is not set"))?;
+        interpolated.push_str(value.as_str());
+        rest = &rest[start + length + 1..];
+    }
+    interpolated.push_str(rest);
+    Ok(interpolated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(name: &str) -> Option<String> {
+        match name {
+            "HOST" => Some(String::from("review-42.suttacentral.net")),
+            "LANGUAGE" => Some(String::from("de")),
+            _ => None,
+        }
+    }
+
+    fn prepare(source: &str, overrides: &[&str]) -> Result<String> {
+        let overrides: Vec<Override> = overrides
+            .iter()
+            .map(|setting| setting.parse().unwrap())
+            .collect();
+        apply_overrides(source, &overrides, variables)
+    }
+
+    #[test]
+    fn parse_override() {
+        let setting: Override = "settings.endpoint=http://localhost:8080/api?a=b"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            setting,
+            Override {
+                key: vec![String::from("settings"), String::from("endpoint")],
+                value: String::from("http://localhost:8080/api?a=b"),
+            }
+        );
+    }
+
+    #[test]
+    fn override_needs_key_and_value() {
+        assert_eq!(
+            "settings.endpoint".parse::<Override>().unwrap_err(),
+            "expected KEY=VALUE but found `settings.endpoint`"
+        );
+        assert_eq!(
+            "settings..endpoint=x".parse::<Override>().unwrap_err(),
+            "invalid key in `settings..endpoint=x`"
+        );
+    }
+
+    #[test]
+    fn interpolates_environment_variables() {
+        let source = prepare(
+            r#"
+            [settings]
+            endpoint = "https://${HOST}/api/search/instant"
+
+            [defaults]
+            selected-languages = ["${LANGUAGE}", "pli"]
+            "#,
+            &[],
+        )
+        .unwrap();
+        assert!(
+            source
+                .contains(r#"endpoint = "https://review-42.suttacentral.net/api/search/instant""#)
+        );
+        assert!(source.contains(r#"selected-languages = ["de", "pli"]"#));
+    }
+
+    #[test]
+    fn error_when_variable_not_set() {
+        let error = prepare(
+            r#"
+            [settings]
+            endpoint = "https://${REVIEW_APP}/api/search/instant"
+            "#,
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Environment variable `REVIEW_APP` is not set"
+        );
+    }
+
+    #[test]
+    fn error_when_interpolation_unclosed() {
+        let error = prepare(
+            r#"
+            [settings]
+            endpoint = "https://${HOST/api/search/instant"
+            "#,
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unclosed `${` in `https://${HOST/api/search/instant`"
+        );
+    }
+
+    #[test]
+    fn overrides_replace_and_add_values() {
+        let source = prepare(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+            "#,
+            &[
+                "settings.endpoint=https://staging.suttacentral.net/api/search/instant",
+                "defaults.limit=50",
+                "defaults.selected-languages=[\"en\"]",
+            ],
+        )
+        .unwrap();
+        let document: toml::Table = toml::from_str(source.as_str()).unwrap();
+        assert_eq!(
+            document["settings"]["endpoint"].as_str(),
+            Some("https://staging.suttacentral.net/api/search/instant")
+        );
+        assert_eq!(document["defaults"]["limit"].as_integer(), Some(50));
+        assert_eq!(
+            document["defaults"]["selected-languages"][0].as_str(),
+            Some("en")
+        );
+    }
+
+    #[test]
+    fn error_when_override_passes_through_value() {
+        let error = prepare(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+            "#,
+            &["settings.endpoint.host=localhost"],
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Cannot set `settings.endpoint.host`: `endpoint` is not a table"
+        );
+    }
+}
@@ -1,6 +1,8 @@
+use serde::Serialize;
 use std::cmp::Ordering;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
 pub enum Rank {
     Sufficient { minimum: usize, actual: usize },
     TooLow { minimum: usize, actual: usize },
@@ -9,13 +9,13 @@ impl TestResult {
     fn main_line(&self) -> String {
         let summary = Summary::from(&self.outcome).to_string();
         let elapsed = format!("{}ms", self.elapsed.as_millis());
-        let description = &self.description;
+        let description = &self.test_case.description;
         format!("{summary:7} {elapsed:6} {description}")
     }
 
-    fn detail_line(&self) -> Option<String> {
+    pub fn detail_line(&self) -> Option<String> {
         match &self.outcome {
-            Outcome::Error { message } => Some(message.to_string()),
+            Outcome::Error { kind: _, message } => Some(message.to_string()),
             Outcome::Success => None,
             Outcome::Found { results: _ } => None,
             Outcome::NotFound { results } => Some(Self::not_found_message(results)),
@@ -32,38 +32,66 @@ impl TestResult {
         }
     }
 
-    fn search_term(results: &SearchResults) -> String {
-        match results {
-            SearchResults::Text {
-                expected,
-                results: _,
-            } => format!("Text hit {expected}"),
-            SearchResults::Dictionary {
-                expected,
-                results: _,
-            } => format!("Dictionary hit {expected}"),
-            SearchResults::Suttaplex {
-                expected,
-                results: _,
-            } => format!("Suttaplex hit {expected}"),
-            SearchResults::Volpage {
-                expected,
-                results: _,
-            } => format!("Volpage hit {expected}"),
+    pub fn colour(&self) -> &'static str {
+        match &self.outcome {
+            Outcome::Error { kind, message: _ } => kind.colour(),
+            outcome => match Summary::from(outcome) {
+                Summary::Passed => "\x1b[32m",
+                _ => "\x1b[31m",
+            },
         }
     }
 
+    pub fn top_results(&self, count: usize) -> Vec<String> {
+        let results = match &self.outcome {
+            Outcome::NotFound { results } => results,
+            Outcome::Ranked {
+                results,
+                rank: Rank::NotFound { minimum: _ } | Rank::TooLow { .. },
+            } => results,
+            _ => return Vec::new(),
+        };
+        results
+            .ids()
+            .into_iter()
+            .take(count)
+            .enumerate()
+            .map(|(index, id)| format!("    {}. {id}", index + 1))
+            .collect()
+    }
+
+    pub fn search_term(results: &SearchResults) -> String {
+        format!("{} hit {}", results.category(), results.expected_id())
+    }
+
     fn not_found_message(results: &SearchResults) -> String {
-        format!("{} not found in search results", Self::search_term(results))
+        format!(
+            "{} not found in search results{}",
+            Self::search_term(results),
+            Self::suggestion(results)
+        )
     }
 
     fn rank_not_found_message(results: &SearchResults, minimum: &usize) -> String {
         format!(
-            "Minium rank {minimum} expected for {} but it was not found",
-            Self::search_term(results)
+            "Minium rank {minimum} expected for {} but it was not found{}",
+            Self::search_term(results),
+            Self::suggestion(results)
         )
     }
 
+    fn suggestion(results: &SearchResults) -> String {
+        let near_misses: Vec<String> = results
+            .near_misses()
+            .iter()
+            .map(|near_miss| near_miss.to_string())
+            .collect();
+        match near_misses.is_empty() {
+            true => String::new(),
+            false => format!(", did you mean {}?", near_misses.join(" or ")),
+        }
+    }
+
     fn rank_too_low_message(results: &SearchResults, minimum: &usize, actual: &usize) -> String {
         format!(
             "Expected {} to have minimum rank of {minimum} but it was found at rank {actual}",
@@ -95,7 +123,9 @@ impl Display for Summary {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::identifiers::SuttaplexUid;
+    use crate::identifiers::{SuttaplexUid, TextUrl};
+    use crate::search_error::ErrorKind;
+    use crate::test_fixtures::test_case;
     use std::io::Write;
     use std::time::Duration;
 
@@ -118,11 +148,13 @@ mod tests {
     #[test]
     fn display_error() {
         let test_result = TestResult {
-            description: String::from("Something will go wrong"),
+            test_case: test_case("Something will go wrong"),
             elapsed: Duration::from_millis(4321),
             outcome: Outcome::Error {
+                kind: ErrorKind::HttpStatus,
                 message: String::from("Something went wrong"),
             },
+            body: None,
         };
 
         assert_eq!(
@@ -137,9 +169,10 @@ mod tests {
     #[test]
     fn display_success() {
         let test_result = TestResult {
-            description: String::from("We will retrieve something"),
+            test_case: test_case("We will retrieve something"),
             elapsed: Duration::from_millis(321),
             outcome: Outcome::Success,
+            body: None,
         };
 
         assert_eq!(
@@ -151,7 +184,7 @@ mod tests {
     #[test]
     fn display_found() {
         let test_result = TestResult {
-            description: String::from("Find suttaplex mn1"),
+            test_case: test_case("Find suttaplex mn1"),
             elapsed: Duration::from_millis(21),
             outcome: Outcome::Found {
                 results: SearchResults::Suttaplex {
@@ -159,6 +192,7 @@ mod tests {
                     results: vec![SuttaplexUid::from("mn1"), SuttaplexUid::from("mn2")],
                 },
             },
+            body: None,
         };
 
         assert_eq!(
@@ -170,7 +204,7 @@ mod tests {
     #[test]
     fn display_not_found() {
         let test_result = TestResult {
-            description: String::from("Find suttaplex mn1"),
+            test_case: test_case("Find suttaplex mn1"),
             elapsed: Duration::from_millis(1),
             outcome: Outcome::NotFound {
                 results: SearchResults::Suttaplex {
@@ -178,6 +212,7 @@ mod tests {
                     results: vec![],
                 },
             },
+            body: None,
         };
 
         assert_eq!(
@@ -189,10 +224,64 @@ mod tests {
         )
     }
 
+    #[test]
+    fn display_not_found_with_near_miss() {
+        let test_result = TestResult {
+            test_case: test_case("Find Bodhi's translation of mn1"),
+            elapsed: Duration::from_millis(1),
+            outcome: Outcome::NotFound {
+                results: SearchResults::Text {
+                    expected: TextUrl::from("/mn1/en/bodhi"),
+                    results: vec![
+                        TextUrl::from("/mn2/en/sujato"),
+                        TextUrl::from("/mn1/en/sujato"),
+                    ],
+                },
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            test_result.to_string(),
+            message(
+                "FAILED  1ms    Find Bodhi's translation of mn1",
+                Some(
+                    "  Text hit /mn1/en/bodhi not found in search results, did you mean /mn1/en/sujato at rank 2?"
+                )
+            )
+        )
+    }
+
+    #[test]
+    fn display_ranked_not_found_with_near_misses() {
+        let test_result = TestResult {
+            test_case: test_case("Wanted rank, but not found"),
+            elapsed: Duration::from_millis(10),
+            outcome: Outcome::Ranked {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("an1.11"),
+                    results: vec![
+                        SuttaplexUid::from("an1.1"),
+                        SuttaplexUid::from("an1.10"),
+                        SuttaplexUid::from("an1.2"),
+                    ],
+                },
+                rank: Rank::NotFound { minimum: 3 },
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            test_result.detail_line().unwrap(),
+            "Minium rank 3 expected for Suttaplex hit an1.11 but it was not found, \
+            did you mean an1.1 at rank 1 or an1.10 at rank 2?"
+        )
+    }
+
     #[test]
     fn display_ranked_not_found() {
         let test_result = TestResult {
-            description: String::from("Wanted rank, but not found"),
+            test_case: test_case("Wanted rank, but not found"),
             elapsed: Duration::from_millis(10),
             outcome: Outcome::Ranked {
                 results: SearchResults::Suttaplex {
@@ -201,6 +290,7 @@ mod tests {
                 },
                 rank: Rank::NotFound { minimum: 3 },
             },
+            body: None,
         };
 
         assert_eq!(
@@ -215,7 +305,7 @@ mod tests {
     #[test]
     fn display_ranked_too_low() {
         let test_result = TestResult {
-            description: String::from("Expecting top rank"),
+            test_case: test_case("Expecting top rank"),
             elapsed: Duration::from_millis(76),
             outcome: Outcome::Ranked {
                 results: SearchResults::Suttaplex {
@@ -227,6 +317,7 @@ mod tests {
                     actual: 2,
                 },
             },
+            body: None,
         };
 
         assert_eq!(
@@ -243,7 +334,7 @@ mod tests {
     #[test]
     fn display_ranked_sufficient() {
         let test_result = TestResult {
-            description: String::from("Expecting top rank"),
+            test_case: test_case("Expecting top rank"),
             elapsed: Duration::from_millis(123),
             outcome: Outcome::Ranked {
                 results: SearchResults::Suttaplex {
@@ -255,6 +346,7 @@ mod tests {
                     actual: 1,
                 },
             },
+            body: None,
         };
 
         assert_eq!(
@@ -262,4 +354,67 @@ mod tests {
             message("PASSED  123ms  Expecting top rank", None)
         );
     }
+
+    #[test]
+    fn top_results_of_failed_rank() {
+        let test_result = TestResult {
+            test_case: test_case("Expecting top rank"),
+            elapsed: Duration::from_millis(76),
+            outcome: Outcome::Ranked {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn3"),
+                    results: vec![
+                        SuttaplexUid::from("mn1"),
+                        SuttaplexUid::from("mn2"),
+                        SuttaplexUid::from("mn3"),
+                    ],
+                },
+                rank: Rank::TooLow {
+                    minimum: 1,
+                    actual: 3,
+                },
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            test_result.top_results(2),
+            vec![String::from("    1. mn1"), String::from("    2. mn2")]
+        );
+    }
+
+    #[test]
+    fn top_results_of_not_found() {
+        let test_result = TestResult {
+            test_case: test_case("Find suttaplex mn1"),
+            elapsed: Duration::from_millis(1),
+            outcome: Outcome::NotFound {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn1"),
+                    results: vec![SuttaplexUid::from("mn2")],
+                },
+            },
+            body: None,
+        };
+
+        assert_eq!(test_result.top_results(5), vec![String::from("    1. mn2")]);
+        assert!(test_result.top_results(0).is_empty());
+    }
+
+    #[test]
+    fn no_top_results_when_passed() {
+        let test_result = TestResult {
+            test_case: test_case("Find suttaplex mn1"),
+            elapsed: Duration::from_millis(21),
+            outcome: Outcome::Found {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn1"),
+                    results: vec![SuttaplexUid::from("mn1")],
+                },
+            },
+            body: None,
+        };
+
+        assert!(test_result.top_results(5).is_empty());
+    }
 }
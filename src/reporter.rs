@@ -0,0 +1,391 @@
+use crate::environment::Environment;
+use crate::request::Request;
+use crate::result_count::ResultCount;
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use crate::test_suite::TestSuite;
+use anyhow::Result;
+use std::io::Write;
+use std::path::Path;
+
+const RESET: &str = "\x1b[0m";
+
+pub trait Reporter {
+    fn begin(&mut self, suite_path: &Path, test_suite: &TestSuite, total: usize) -> Result<()>;
+    fn result(&mut self, result: &TestResult) -> Result<()>;
+    fn note(&mut self, note: &str) -> Result<()>;
+    fn info(&mut self, info: &str) -> Result<()>;
+    fn finish(&mut self, count: &ResultCount) -> Result<()>;
+    fn total(&mut self, count: &ResultCount) -> Result<()>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+    VeryVerbose,
+}
+
+pub struct TextReporter<W: Write> {
+    writer: W,
+    verbosity: Verbosity,
+    show_top: usize,
+    colour: bool,
+    environment: Environment,
+    group: Option<String>,
+    notes: Vec<String>,
+}
+
+impl<W: Write> TextReporter<W> {
+    pub fn new(writer: W, verbosity: Verbosity, show_top: usize, colour: bool) -> Self {
+        Self {
+            writer,
+            verbosity,
+            show_top,
+            colour,
+            environment: Environment::default(),
+            group: None,
+            notes: Vec::new(),
+        }
+    }
+
+    fn write_group_heading(&mut self, result: &TestResult) -> Result<()> {
+        if result.test_case.group == self.group {
+            return Ok(());
+        }
+        self.group = result.test_case.group.clone();
+        if let Some(group) = &self.group {
+            writeln!(self.writer, "Group {group}")?;
+        }
+        Ok(())
+    }
+
+    fn write_summary(&mut self, result: &TestResult) -> Result<()> {
+        match self.colour {
+            true => writeln!(
+                self.writer,
+                "{}{}{RESET}",
+                result.colour(),
+                result.to_string().trim_end()
+            )?,
+            false => write!(self.writer, "{result}")?,
+        }
+        Ok(())
+    }
+
+    fn write_request(&mut self, result: &TestResult) -> Result<()> {
+        let request = Request::new(&self.environment, &result.test_case);
+        writeln!(self.writer, "    POST {}", request.url()?)?;
+        writeln!(self.writer, "    Body: {}", request.body()?)?;
+        Ok(())
+    }
+
+    fn write_response(&mut self, result: &TestResult) -> Result<()> {
+        match &result.body {
+            Some(body) => writeln!(self.writer, "    Response: {}", body.trim_end())?,
+            None => writeln!(self.writer, "    No response body")?,
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Reporter for TextReporter<W> {
+    fn begin(&mut self, _suite_path: &Path, test_suite: &TestSuite, _total: usize) -> Result<()> {
+        self.environment = test_suite.environment();
+        if self.verbosity > Verbosity::Quiet {
+            writeln!(self.writer, "{}", test_suite.headline())?;
+            writeln!(self.writer)?;
+        }
+        Ok(())
+    }
+
+    fn result(&mut self, result: &TestResult) -> Result<()> {
+        let passed = Summary::from(&result.outcome) == Summary::Passed;
+        if passed && self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
+        self.write_group_heading(result)?;
+        self.write_summary(result)?;
+        for line in result.top_results(self.show_top) {
+            writeln!(self.writer, "{line}")?;
+        }
+        if self.verbosity >= Verbosity::Verbose {
+            self.write_request(result)?;
+        }
+        if !passed && self.verbosity == Verbosity::VeryVerbose {
+            self.write_response(result)?;
+        }
+        Ok(())
+    }
+
+    fn note(&mut self, note: &str) -> Result<()> {
+        self.notes.push(String::from(note.trim_end()));
+        Ok(())
+    }
+
+    fn info(&mut self, info: &str) -> Result<()> {
+        match self.verbosity {
+            Verbosity::Quiet => Ok(()),
+            _ => self.note(info),
+        }
+    }
+
+    fn finish(&mut self, count: &ResultCount) -> Result<()> {
+        writeln!(self.writer, "{count}")?;
+        for note in &self.notes {
+            writeln!(self.writer)?;
+            writeln!(self.writer, "{note}")?;
+        }
+        Ok(())
+    }
+
+    fn total(&mut self, count: &ResultCount) -> Result<()> {
+        writeln!(self.writer, "All suites: {count}")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::search_error::ErrorKind;
+    use crate::search_results::SearchResults;
+    use crate::test_case::TestCase;
+    use crate::test_fixtures::test_case;
+    use std::time::Duration;
+
+    fn suite() -> TestSuite {
+        TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+            delay = 10
+
+            [[test-case]]
+            description = "Search is successful"
+            query = "dhamma"
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn result() -> TestResult {
+        TestResult {
+            test_case: TestCase {
+                query: String::from("dhamma"),
+                ..test_case("Search is successful")
+            },
+            elapsed: Duration::from_millis(679),
+            outcome: Outcome::Success,
+            body: None,
+        }
+    }
+
+    #[test]
+    fn text_report() {
+        let mut output = Vec::new();
+        let mut count = ResultCount::new();
+        count.add(&Outcome::Success);
+
+        let mut reporter = TextReporter::new(&mut output, Verbosity::Normal, 5, false);
+        reporter
+            .begin(Path::new("suite.toml"), &suite(), 1)
+            .unwrap();
+        reporter.result(&result()).unwrap();
+        reporter.note("Compared with baseline\n").unwrap();
+        reporter.finish(&count).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Running tests against endpoint http://localhost/api/search/instant with 10ms delay\n\
+            \n\
+            PASSED  679ms  Search is successful\n\
+            1 passed, 0 failed, 0 encountered an error\n\
+            \n\
+            Compared with baseline\n"
+        );
+    }
+
+    #[test]
+    fn text_report_has_group_headings() {
+        let mut output = Vec::new();
+        let grouped = |group: &str| TestResult {
+            test_case: TestCase {
+                group: Some(String::from(group)),
+                ..result().test_case
+            },
+            ..result()
+        };
+
+        let mut reporter = TextReporter::new(&mut output, Verbosity::Normal, 5, false);
+        reporter.result(&result()).unwrap();
+        reporter.result(&grouped("German")).unwrap();
+        reporter.result(&grouped("German")).unwrap();
+        reporter.result(&grouped("Partial")).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "PASSED  679ms  Search is successful\n\
+            Group German\n\
+            PASSED  679ms  Search is successful\n\
+            PASSED  679ms  Search is successful\n\
+            Group Partial\n\
+            PASSED  679ms  Search is successful\n"
+        );
+    }
+
+    #[test]
+    fn text_report_colours_summaries() {
+        let mut output = Vec::new();
+        let error = TestResult {
+            outcome: Outcome::Error {
+                kind: ErrorKind::HttpStatus,
+                message: String::from("Expected status code to be 200 OK but got 502 Bad Gateway"),
+            },
+            ..result()
+        };
+
+        let mut reporter = TextReporter::new(&mut output, Verbosity::Normal, 5, true);
+        reporter.result(&error).unwrap();
+        reporter.result(&result()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\x1b[33mERROR   679ms  Search is successful\n\
+            \x20 Expected status code to be 200 OK but got 502 Bad Gateway\x1b[0m\n\
+            \x1b[32mPASSED  679ms  Search is successful\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn text_report_shows_top_results_of_failures() {
+        let mut output = Vec::new();
+        let failure = TestResult {
+            outcome: Outcome::NotFound {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn1"),
+                    results: vec![
+                        SuttaplexUid::from("mn2"),
+                        SuttaplexUid::from("mn3"),
+                        SuttaplexUid::from("mn4"),
+                    ],
+                },
+            },
+            ..result()
+        };
+
+        let mut reporter = TextReporter::new(&mut output, Verbosity::Normal, 2, false);
+        reporter.result(&failure).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "FAILED  679ms  Search is successful\n\
+            \x20 Suttaplex hit mn1 not found in search results\n\
+            \x20   1. mn2\n\
+            \x20   2. mn3\n"
+        );
+    }
+
+    fn failure() -> TestResult {
+        TestResult {
+            outcome: Outcome::NotFound {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn1"),
+                    results: vec![],
+                },
+            },
+            body: Some(String::from("{\"suttaplex\": []}")),
+            ..result()
+        }
+    }
+
+    fn report_at(verbosity: Verbosity) -> String {
+        let mut output = Vec::new();
+        let mut count = ResultCount::new();
+        count.add(&result().outcome);
+        count.add(&failure().outcome);
+
+        let mut reporter = TextReporter::new(&mut output, verbosity, 5, false);
+        reporter
+            .begin(Path::new("suite.toml"), &suite(), 2)
+            .unwrap();
+        reporter.result(&result()).unwrap();
+        reporter.result(&failure()).unwrap();
+        reporter.info("Total time 1.36s").unwrap();
+        reporter.finish(&count).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn quiet_report_only_has_failures_and_summary() {
+        assert_eq!(
+            report_at(Verbosity::Quiet),
+            "FAILED  679ms  Search is successful\n\
+            \x20 Suttaplex hit mn1 not found in search results\n\
+            1 passed, 1 failed, 0 encountered an error\n"
+        );
+    }
+
+    #[test]
+    fn quiet_report_keeps_notes_but_leaves_out_information() {
+        let mut output = Vec::new();
+        let mut reporter = TextReporter::new(&mut output, Verbosity::Quiet, 5, false);
+        reporter
+            .begin(Path::new("suite.toml"), &suite(), 1)
+            .unwrap();
+        reporter.info("Total time 1.36s").unwrap();
+        reporter
+            .note("Blessed `Too low`: min-rank 1 -> 4\n")
+            .unwrap();
+        reporter
+            .note("Compared with baseline\n\nNewly failing\n  Search is successful")
+            .unwrap();
+        reporter.finish(&ResultCount::new()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "0 passed, 0 failed, 0 encountered an error\n\
+            \n\
+            Blessed `Too low`: min-rank 1 -> 4\n\
+            \n\
+            Compared with baseline\n\
+            \n\
+            Newly failing\n\
+            \x20 Search is successful\n"
+        );
+        assert!(report_at(Verbosity::Normal).ends_with("\nTotal time 1.36s\n"));
+    }
+
+    #[test]
+    fn verbose_report_has_requests() {
+        assert_eq!(
+            report_at(Verbosity::Verbose),
+            "Running tests against endpoint http://localhost/api/search/instant with 10ms delay\n\
+            \n\
+            PASSED  679ms  Search is successful\n\
+            \x20   POST http://localhost/api/search/instant?limit=10&query=dhamma&language=en&restrict=all&matchpartial=false\n\
+            \x20   Body: [\"en\"]\n\
+            FAILED  679ms  Search is successful\n\
+            \x20 Suttaplex hit mn1 not found in search results\n\
+            \x20   POST http://localhost/api/search/instant?limit=10&query=dhamma&language=en&restrict=all&matchpartial=false\n\
+            \x20   Body: [\"en\"]\n\
+            1 passed, 1 failed, 0 encountered an error\n\
+            \n\
+            Total time 1.36s\n"
+        );
+    }
+
+    #[test]
+    fn very_verbose_report_has_response_of_failures() {
+        let report = report_at(Verbosity::VeryVerbose);
+        assert_eq!(report.matches("    Response: ").count(), 1);
+        assert!(report.contains(
+            "\x20   Body: [\"en\"]\n\
+            \x20   Response: {\"suttaplex\": []}\n\
+            1 passed"
+        ));
+    }
+}
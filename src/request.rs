@@ -1,31 +1,54 @@
+use crate::environment::Environment;
+use crate::search_error::{ErrorKind, SearchError};
 use crate::test_case::TestCase;
 use anyhow::{Context, Result};
 use reqwest::blocking::{Client, RequestBuilder, Response};
 
 pub struct Request {
-    endpoint: String,
+    environment: Environment,
     test_case: TestCase,
 }
 
 impl Request {
-    pub fn new(endpoint: String, test_case: &TestCase) -> Request {
+    pub fn new(environment: &Environment, test_case: &TestCase) -> Request {
         Request {
-            endpoint: endpoint,
+            environment: environment.clone(),
             test_case: test_case.clone(),
         }
     }
 
     pub fn send(&self) -> Result<Response> {
-        self.build_request()
-            .send()
-            .context("Error sending HTTP request")
+        self.build_request().send().context(SearchError::new(
+            ErrorKind::Transport,
+            "Error sending HTTP request",
+        ))
+    }
+
+    pub fn url(&self) -> Result<String> {
+        let request = self
+            .build_request()
+            .build()
+            .context("Error building HTTP request")?;
+        Ok(request.url().to_string())
+    }
+
+    pub fn body(&self) -> Result<String> {
+        serde_json::to_string(&self.test_case.selected_languages)
+            .context("Error serialising HTTP request body")
     }
 
     fn build_request(&self) -> RequestBuilder {
-        Client::new()
-            .post(self.endpoint.as_str())
+        let mut builder = Client::new()
+            .post(self.environment.endpoint.as_str())
             .query(&self.parameters())
-            .json(&self.test_case.selected_languages)
+            .json(&self.test_case.selected_languages);
+        if let Some(timeout) = self.environment.timeout() {
+            builder = builder.timeout(timeout);
+        }
+        for (name, value) in &self.environment.headers {
+            builder = builder.header(name, value);
+        }
+        builder
     }
 
     fn parameters(&self) -> Vec<(String, String)> {
@@ -48,6 +71,8 @@ impl Request {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_case::Origin;
+    use std::time::Duration;
 
     fn test_case() -> TestCase {
         TestCase {
@@ -59,13 +84,16 @@ mod tests {
             match_partial: false,
             selected_languages: vec![String::from("en"), String::from("pli")],
             expected: None,
+            tags: Vec::new(),
+            group: None,
+            origin: Origin::Suite,
         }
     }
 
     #[test]
     fn builds_url() {
         let url = Request::new(
-            String::from("http://localhost/api/search/instant"),
+            &Environment::new(String::from("http://localhost/api/search/instant")),
             &test_case(),
         )
         .build_request()
@@ -84,7 +112,7 @@ mod tests {
     fn has_correct_body() {
         let body_string = str::from_utf8(
             Request::new(
-                String::from("http://localhost/api/search/instant"),
+                &Environment::new(String::from("http://localhost/api/search/instant")),
                 &test_case(),
             )
             .build_request()
@@ -100,4 +128,42 @@ mod tests {
 
         assert_eq!(body_string, "[\"en\",\"pli\"]");
     }
+
+    #[test]
+    fn describes_url_and_body() {
+        let request = Request::new(
+            &Environment::new(String::from("http://localhost/api/search/instant")),
+            &test_case(),
+        );
+
+        assert_eq!(
+            request.url().unwrap(),
+            "http://localhost/api/search/instant?limit=1&query=adze&language=en&restrict=all&matchpartial=false"
+        );
+        assert_eq!(request.body().unwrap(), "[\"en\",\"pli\"]");
+    }
+
+    #[test]
+    fn sends_environment_headers_and_timeout() {
+        let environment = Environment {
+            timeout: Some(5000),
+            headers: [(
+                String::from("Authorization"),
+                String::from("Basic c3RhZ2luZw=="),
+            )]
+            .into_iter()
+            .collect(),
+            ..Environment::new(String::from("http://localhost/api/search/instant"))
+        };
+        let request = Request::new(&environment, &test_case())
+            .build_request()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.headers().get("Authorization").unwrap(),
+            "Basic c3RhZ2luZw=="
+        );
+        assert_eq!(request.timeout(), Some(&Duration::from_millis(5000)));
+    }
 }
@@ -1,11 +1,18 @@
+use crate::outcome::Outcome;
+use crate::search_error::ErrorKind;
 use crate::summary::Summary;
+use serde::Serialize;
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct ResultCount {
     passed: usize,
     failed: usize,
     error: usize,
+    transport_error: usize,
+    http_status_error: usize,
+    parse_error: usize,
 }
 
 impl ResultCount {
@@ -14,15 +21,38 @@ impl ResultCount {
             passed: 0,
             failed: 0,
             error: 0,
+            transport_error: 0,
+            http_status_error: 0,
+            parse_error: 0,
         }
     }
 
-    pub fn add(&mut self, summary: &Summary) {
-        match summary {
+    pub fn all_passed(&self) -> bool {
+        self.failed == 0 && self.error == 0
+    }
+
+    pub fn add_count(&mut self, other: &ResultCount) {
+        self.passed += other.passed;
+        self.failed += other.failed;
+        self.error += other.error;
+        self.transport_error += other.transport_error;
+        self.http_status_error += other.http_status_error;
+        self.parse_error += other.parse_error;
+    }
+
+    pub fn add(&mut self, outcome: &Outcome) {
+        match Summary::from(outcome) {
             Summary::Passed => self.passed += 1,
             Summary::Failed => self.failed += 1,
             Summary::Error => self.error += 1,
         }
+        if let Outcome::Error { kind, message: _ } = outcome {
+            match kind {
+                ErrorKind::Transport => self.transport_error += 1,
+                ErrorKind::HttpStatus => self.http_status_error += 1,
+                ErrorKind::Parse => self.parse_error += 1,
+            }
+        }
     }
 }
 
@@ -32,13 +62,30 @@ impl Display for ResultCount {
             f,
             "{} passed, {} failed, {} encountered an error",
             self.passed, self.failed, self.error
-        )
+        )?;
+        if self.error > 0 {
+            write!(
+                f,
+                " ({} transport, {} HTTP status, {} parse)",
+                self.transport_error, self.http_status_error, self.parse_error
+            )?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::search_results::SearchResults;
+
+    fn error(kind: ErrorKind) -> Outcome {
+        Outcome::Error {
+            kind,
+            message: String::from("Something went wrong"),
+        }
+    }
 
     #[test]
     fn initialise_result_count() {
@@ -48,7 +95,10 @@ mod tests {
             ResultCount {
                 passed: 0,
                 failed: 0,
-                error: 0
+                error: 0,
+                transport_error: 0,
+                http_status_error: 0,
+                parse_error: 0,
             }
         );
     }
@@ -56,26 +106,71 @@ mod tests {
     #[test]
     fn add_one_of_each() {
         let mut counter = ResultCount::new();
-        counter.add(&Summary::Passed);
-        counter.add(&Summary::Failed);
-        counter.add(&Summary::Error);
+        counter.add(&Outcome::Success);
+        counter.add(&Outcome::NotFound {
+            results: SearchResults::Suttaplex {
+                expected: SuttaplexUid::from("mn1"),
+                results: Vec::new(),
+            },
+        });
+        counter.add(&error(ErrorKind::Transport));
+        counter.add(&error(ErrorKind::HttpStatus));
+        counter.add(&error(ErrorKind::Parse));
 
         assert_eq!(
             counter,
             ResultCount {
                 passed: 1,
                 failed: 1,
+                error: 3,
+                transport_error: 1,
+                http_status_error: 1,
+                parse_error: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn combine_counts() {
+        let mut first = ResultCount::new();
+        first.add(&Outcome::Success);
+        let mut second = ResultCount::new();
+        second.add(&error(ErrorKind::Parse));
+
+        first.add_count(&second);
+
+        assert_eq!(
+            first,
+            ResultCount {
+                passed: 1,
+                failed: 0,
                 error: 1,
+                transport_error: 0,
+                http_status_error: 0,
+                parse_error: 1,
             }
         );
     }
 
+    #[test]
+    fn all_passed() {
+        let mut count = ResultCount::new();
+        assert!(count.all_passed());
+        count.add(&Outcome::Success);
+        assert!(count.all_passed());
+        count.add(&error(ErrorKind::Transport));
+        assert!(!count.all_passed());
+    }
+
     #[test]
     fn display() {
         let result_count = ResultCount {
             passed: 1,
             failed: 2,
             error: 0,
+            transport_error: 0,
+            http_status_error: 0,
+            parse_error: 0,
         };
 
         assert_eq!(
@@ -83,4 +178,17 @@ mod tests {
             "1 passed, 2 failed, 0 encountered an error"
         )
     }
+
+    #[test]
+    fn display_error_kinds() {
+        let mut result_count = ResultCount::new();
+        result_count.add(&error(ErrorKind::HttpStatus));
+        result_count.add(&error(ErrorKind::HttpStatus));
+        result_count.add(&error(ErrorKind::Parse));
+
+        assert_eq!(
+            result_count.to_string(),
+            "0 passed, 0 failed, 3 encountered an error (0 transport, 2 HTTP status, 1 parse)"
+        )
+    }
 }
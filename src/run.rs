@@ -1,9 +1,7 @@
-use crate::test_case::TestCase;
-use crate::test_suite::TestSuite;
-
+use crate::comparison::Comparison;
 use crate::search_service::SearchService;
+use crate::test_case::TestCase;
 use crate::test_result::TestResult;
-use anyhow::Result;
 
 #[derive(Debug)]
 pub struct Runner<T: SearchService> {
@@ -12,13 +10,15 @@ pub struct Runner<T: SearchService> {
 }
 
 impl<T: SearchService> Runner<T> {
-    pub fn new(suite: &TestSuite, search_service: T) -> Result<Self> {
-        let test_cases = suite.test_cases().collect::<Result<Vec<_>>>()?;
-
-        Ok(Self {
+    pub fn new(test_cases: Vec<TestCase>, search_service: T) -> Self {
+        Self {
             search_service,
             test_cases,
-        })
+        }
+    }
+
+    pub fn test_count(&self) -> usize {
+        self.test_cases.len()
     }
 
     pub fn run(&self) -> impl Iterator<Item = TestResult> {
@@ -33,10 +33,41 @@ impl<T: SearchService> Runner<T> {
     }
 }
 
+#[derive(Debug)]
+pub struct ComparisonRunner<T: SearchService> {
+    primary: T,
+    secondary: T,
+    test_cases: Vec<TestCase>,
+}
+
+impl<T: SearchService> ComparisonRunner<T> {
+    pub fn new(test_cases: Vec<TestCase>, primary: T, secondary: T) -> Self {
+        Self {
+            primary,
+            secondary,
+            test_cases,
+        }
+    }
+
+    pub fn run(&self) -> impl Iterator<Item = Comparison> {
+        self.test_cases
+            .iter()
+            .map(|test_case| self.run_test(test_case))
+    }
+
+    fn run_test(&self, test_case: &TestCase) -> Comparison {
+        let primary = self.primary.search(test_case);
+        let secondary = self.secondary.search(test_case);
+        Comparison::new(test_case, primary, secondary)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_suite::TestSuite;
     use crate::timed_response::TimedResponse;
+    use anyhow::Result;
     use std::cell::RefCell;
     use std::time::Duration;
 
@@ -59,6 +90,10 @@ mod tests {
         }
     }
 
+    fn test_cases(suite: &TestSuite) -> Vec<TestCase> {
+        suite.test_cases().collect::<Result<Vec<_>>>().unwrap()
+    }
+
     #[test]
     fn good_test_gives_new_runner() {
         let suite = TestSuite::load_from_string(
@@ -77,36 +112,51 @@ mod tests {
             "#,
         )
         .unwrap();
-        let runner = Runner::new(&suite, FakeSearchService::new(Vec::new())).unwrap();
-        assert_eq!(runner.test_cases.len(), 1)
+        let runner = Runner::new(test_cases(&suite), FakeSearchService::new(Vec::new()));
+        assert_eq!(runner.test_cases.len(), 1);
+        assert_eq!(runner.test_count(), 1)
     }
 
     #[test]
-    fn bad_test_fails_to_give_a_runner() {
+    fn run_a_test() {
         let suite = TestSuite::load_from_string(
             r#"
             [settings]
             endpoint = "http://localhost/api/search/instant"
 
+            [defaults]
+            selected-languages = ["pli", "en"]
+            match-partial = false
+            limit = 50
+            site-language = "en"
+            restrict = "all"
+
             [[test-case]]
-            description = "Search for the metta sutta in English and Pali"
+            description = "First search"
             query = "metta"
             "#,
         )
         .unwrap();
-        let error = Runner::new(&suite, FakeSearchService::new(Vec::new())).unwrap_err();
-        assert_eq!(
-            error.to_string(),
-            "Test case `Search for the metta sutta in English and Pali` missing `site-language` and no default provided."
-        );
+
+        let service = FakeSearchService::new(vec![TimedResponse {
+            elapsed: Duration::from_secs(3),
+            json: Ok(String::from(r#"{ "suttaplex": [ { "uid": "mn1" } ] } "#)),
+            body: None,
+        }]);
+
+        let runner = Runner::new(test_cases(&suite), service);
+        let test_result = runner.run().next().unwrap();
+
+        assert_eq!(test_result.elapsed, Duration::from_secs(3))
     }
 
     #[test]
-    fn run_a_test() {
+    fn run_a_comparison() {
         let suite = TestSuite::load_from_string(
             r#"
             [settings]
             endpoint = "http://localhost/api/search/instant"
+            compare-endpoint = "http://staging/api/search/instant"
 
             [defaults]
             selected-languages = ["pli", "en"]
@@ -118,18 +168,28 @@ mod tests {
             [[test-case]]
             description = "First search"
             query = "metta"
+            expected.suttaplex = "mn1"
             "#,
         )
         .unwrap();
 
-        let service = FakeSearchService::new(vec![TimedResponse {
+        let primary = FakeSearchService::new(vec![TimedResponse {
             elapsed: Duration::from_secs(3),
             json: Ok(String::from(r#"{ "suttaplex": [ { "uid": "mn1" } ] } "#)),
+            body: None,
         }]);
 
-        let runner = Runner::new(&suite, service).unwrap();
-        let test_result = runner.run().next().unwrap();
+        let secondary = FakeSearchService::new(vec![TimedResponse {
+            elapsed: Duration::from_secs(2),
+            json: Ok(String::from(r#"{ "suttaplex": [] } "#)),
+            body: None,
+        }]);
 
-        assert_eq!(test_result.elapsed, Duration::from_secs(3))
+        let runner = ComparisonRunner::new(test_cases(&suite), primary, secondary);
+        let comparison = runner.run().next().unwrap();
+
+        assert_eq!(comparison.primary.elapsed, Duration::from_secs(3));
+        assert_eq!(comparison.secondary.elapsed, Duration::from_secs(2));
+        assert!(comparison.outcome_changed());
     }
 }
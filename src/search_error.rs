@@ -0,0 +1,94 @@
+use anyhow::Error;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorKind {
+    Transport,
+    HttpStatus,
+    Parse,
+}
+
+impl ErrorKind {
+    pub fn of(error: &Error) -> ErrorKind {
+        error
+            .downcast_ref::<SearchError>()
+            .map(|search_error| search_error.kind)
+            .unwrap_or(ErrorKind::Transport)
+    }
+
+    pub fn colour(&self) -> &'static str {
+        match self {
+            ErrorKind::Transport => "\x1b[35m",
+            ErrorKind::HttpStatus => "\x1b[33m",
+            ErrorKind::Parse => "\x1b[36m",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Transport => f.write_str("transport"),
+            ErrorKind::HttpStatus => f.write_str("HTTP status"),
+            ErrorKind::Parse => f.write_str("parse"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SearchError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl SearchError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for SearchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message.as_str())
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{Context, anyhow};
+
+    #[test]
+    fn kind_of_search_error() {
+        let error = anyhow!(SearchError::new(ErrorKind::HttpStatus, "Bad gateway"));
+        assert_eq!(ErrorKind::of(&error), ErrorKind::HttpStatus);
+        assert_eq!(error.to_string(), "Bad gateway");
+    }
+
+    #[test]
+    fn kind_of_error_with_search_error_context() {
+        let error = Err::<(), _>(anyhow!("expected value at line 1 column 1"))
+            .context(SearchError::new(ErrorKind::Parse, "Could not parse"))
+            .unwrap_err();
+        assert_eq!(ErrorKind::of(&error), ErrorKind::Parse);
+        assert_eq!(
+            format!("{error:#}"),
+            "Could not parse: expected value at line 1 column 1"
+        );
+    }
+
+    #[test]
+    fn uncategorised_error_is_transport() {
+        assert_eq!(
+            ErrorKind::of(&anyhow!("Connection refused")),
+            ErrorKind::Transport
+        );
+    }
+}
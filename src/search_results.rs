@@ -1,4 +1,5 @@
 use crate::identifiers::{DictionaryUrl, SearchResultKey, SuttaplexUid, TextUrl, VolpageReference};
+use crate::near_miss::NearMiss;
 use crate::response::dictionary::dictionary_results;
 use crate::response::suttaplex::suttaplex_results;
 use crate::response::texts::text_results;
@@ -65,6 +66,44 @@ impl SearchResults {
         }
     }
 
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::Text { .. } => "Text",
+            Self::Dictionary { .. } => "Dictionary",
+            Self::Suttaplex { .. } => "Suttaplex",
+            Self::Volpage { .. } => "Volpage",
+        }
+    }
+
+    pub fn expected_id(&self) -> String {
+        match self {
+            Self::Text { expected, .. } => expected.to_string(),
+            Self::Dictionary { expected, .. } => expected.to_string(),
+            Self::Suttaplex { expected, .. } => expected.to_string(),
+            Self::Volpage { expected, .. } => expected.to_string(),
+        }
+    }
+
+    pub fn ids(&self) -> Vec<String> {
+        match self {
+            Self::Text { results, .. } => Self::ids_of(results),
+            Self::Dictionary { results, .. } => Self::ids_of(results),
+            Self::Suttaplex { results, .. } => Self::ids_of(results),
+            Self::Volpage { results, .. } => Self::ids_of(results),
+        }
+    }
+
+    pub fn near_misses(&self) -> Vec<NearMiss> {
+        match self.found() {
+            true => Vec::new(),
+            false => NearMiss::find(self.expected_id().as_str(), &self.ids()),
+        }
+    }
+
+    pub fn ids_of<T: ToString>(results: &[T]) -> Vec<String> {
+        results.iter().map(|result| result.to_string()).collect()
+    }
+
     fn rank_in_results<T: PartialEq>(item: &T, results: &[T]) -> Option<usize> {
         results
             .iter()
@@ -337,6 +376,30 @@ mod tests {
         assert_eq!(results.rank(), None)
     }
 
+    #[test]
+    fn ids_are_listed_in_rank_order() {
+        let results = SearchResults::Text {
+            expected: TextUrl::from("/mn1/en/bodhi"),
+            results: vec![
+                TextUrl::from("/mn1/en/sujato"),
+                TextUrl::from("/mn1/en/bodhi"),
+            ],
+        };
+
+        assert_eq!(results.ids(), vec!["/mn1/en/sujato", "/mn1/en/bodhi"]);
+    }
+
+    #[test]
+    fn category_and_expected_id() {
+        let results = SearchResults::Dictionary {
+            expected: DictionaryUrl::from("/define/metta"),
+            results: Vec::new(),
+        };
+
+        assert_eq!(results.category(), "Dictionary");
+        assert_eq!(results.expected_id(), "/define/metta");
+    }
+
     #[test]
     fn volpage_has_no_rank() {
         let results = SearchResults::Volpage {
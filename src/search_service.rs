@@ -1,3 +1,4 @@
+use crate::environment::Environment;
 use crate::request::Request;
 use crate::test_case::TestCase;
 use crate::timed_response::TimedResponse;
@@ -9,19 +10,19 @@ pub trait SearchService {
 
 #[derive(Debug)]
 pub struct LiveSearchService {
-    endpoint: String,
+    environment: Environment,
 }
 
 impl LiveSearchService {
-    pub fn new(endpoint: String) -> Self {
-        Self { endpoint }
+    pub fn new(environment: Environment) -> Self {
+        Self { environment }
     }
 }
 
 impl SearchService for LiveSearchService {
     fn search(&self, test_case: &TestCase) -> TimedResponse {
         let start = Instant::now();
-        let response = Request::new(self.endpoint.clone(), test_case).send();
+        let response = Request::new(&self.environment, test_case).send();
         let elapsed = start.elapsed();
         TimedResponse::new(elapsed, response)
     }
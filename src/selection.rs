@@ -0,0 +1,219 @@
+use crate::source_location::combine;
+use crate::test_case::TestCase;
+use crate::test_suite::TestSuite;
+use anyhow::Result;
+use regex::Regex;
+
+#[derive(Clone, Debug, Default)]
+pub struct Selection {
+    pub tags: Vec<String>,
+    pub excluded_tags: Vec<String>,
+    pub descriptions: Vec<String>,
+    pub pattern: Option<Regex>,
+}
+
+impl Selection {
+    pub fn is_filtering(&self) -> bool {
+        !self.tags.is_empty()
+            || !self.excluded_tags.is_empty()
+            || !self.descriptions.is_empty()
+            || self.pattern.is_some()
+    }
+
+    pub fn selects(&self, test_case: &TestCase) -> bool {
+        let included = self.tags.is_empty() || Self::has_any(test_case, &self.tags);
+        included
+            && !Self::has_any(test_case, &self.excluded_tags)
+            && self.matches_description(test_case.description.as_str())
+    }
+
+    fn matches_description(&self, description: &str) -> bool {
+        let contains = self.descriptions.is_empty()
+            || self
+                .descriptions
+                .iter()
+                .any(|substring| description.contains(substring.as_str()));
+        let matches = match &self.pattern {
+            Some(pattern) => pattern.is_match(description),
+            None => true,
+        };
+        contains && matches
+    }
+
+    pub fn test_cases(&self, suite: &TestSuite) -> Result<Vec<TestCase>> {
+        let mut test_cases = Vec::new();
+        let mut errors = Vec::new();
+        for test_case in suite.test_cases() {
+            match test_case {
+                Ok(test_case) if self.selects(&test_case) => test_cases.push(test_case),
+                Ok(_) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(test_cases),
+            false => Err(combine(errors)),
+        }
+    }
+
+    fn has_any(test_case: &TestCase, tags: &[String]) -> bool {
+        test_case.tags.iter().any(|tag| tags.contains(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suite() -> TestSuite {
+        TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            selected-languages = ["pli", "en"]
+            match-partial = false
+            limit = 50
+            site-language = "en"
+            restrict = "all"
+
+            [[test-case]]
+            description = "Smoke test"
+            query = "metta"
+            tags = ["smoke", "pali"]
+
+            [[test-case]]
+            description = "Volpage"
+            query = "PTS SN ii 1"
+            tags = ["volpage"]
+
+            [[test-case]]
+            description = "Untagged"
+            query = "adze"
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn selected(selection: Selection) -> Vec<String> {
+        selection
+            .test_cases(&suite())
+            .unwrap()
+            .into_iter()
+            .map(|test_case| test_case.description)
+            .collect()
+    }
+
+    fn strings(strings: &[&str]) -> Vec<String> {
+        strings.iter().map(|string| String::from(*string)).collect()
+    }
+
+    #[test]
+    fn everything_selected_by_default() {
+        assert_eq!(
+            selected(Selection::default()),
+            vec!["Smoke test", "Volpage", "Untagged"]
+        );
+    }
+
+    #[test]
+    fn select_by_any_tag() {
+        assert_eq!(
+            selected(Selection {
+                tags: strings(&["pali", "volpage"]),
+                ..Selection::default()
+            }),
+            vec!["Smoke test", "Volpage"]
+        );
+    }
+
+    #[test]
+    fn exclude_by_tag() {
+        assert_eq!(
+            selected(Selection {
+                excluded_tags: strings(&["smoke"]),
+                ..Selection::default()
+            }),
+            vec!["Volpage", "Untagged"]
+        );
+    }
+
+    #[test]
+    fn exclusion_wins_over_selection() {
+        assert_eq!(
+            selected(Selection {
+                tags: strings(&["smoke"]),
+                excluded_tags: strings(&["pali"]),
+                ..Selection::default()
+            }),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn select_by_description_substring() {
+        assert_eq!(
+            selected(Selection {
+                descriptions: strings(&["Volpage", "tagged"]),
+                ..Selection::default()
+            }),
+            vec!["Volpage", "Untagged"]
+        );
+    }
+
+    #[test]
+    fn select_by_description_pattern() {
+        assert_eq!(
+            selected(Selection {
+                pattern: Some(Regex::new("^(Smoke|Volpage)").unwrap()),
+                ..Selection::default()
+            }),
+            vec!["Smoke test", "Volpage"]
+        );
+    }
+
+    #[test]
+    fn substring_and_tag_must_both_match() {
+        assert_eq!(
+            selected(Selection {
+                tags: strings(&["smoke"]),
+                descriptions: strings(&["Volpage"]),
+                ..Selection::default()
+            }),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn filtering() {
+        assert!(!Selection::default().is_filtering());
+        assert!(
+            Selection {
+                descriptions: strings(&["metta"]),
+                ..Selection::default()
+            }
+            .is_filtering()
+        );
+    }
+
+    #[test]
+    fn bad_test_fails_to_give_test_cases() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Search for the metta sutta in English and Pali"
+            query = "metta"
+            "#,
+        )
+        .unwrap();
+        let error = Selection::default().test_cases(&suite).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:5:13: Test case `Search for the metta sutta in English and Pali` missing `site-language` and no default provided.\n  |\n5 |             [[test-case]]\n  |             ^"
+        );
+    }
+}
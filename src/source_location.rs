@@ -0,0 +1,129 @@
+use anyhow::{Error, anyhow};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceLocation {
+    path: PathBuf,
+    line: usize,
+    column: usize,
+    text: String,
+}
+
+impl SourceLocation {
+    pub fn new(path: &Path, source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        Self {
+            path: path.to_path_buf(),
+            line: before.matches('\n').count() + 1,
+            column: source[start..offset].chars().count() + 1,
+            text: source[start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    pub fn error(&self, message: impl Display) -> Error {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let indent: String = self
+            .text
+            .chars()
+            .take(self.column - 1)
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect();
+        anyhow!(
+            "{self}: {message}\n{margin} |\n{number} | {}\n{margin} | {indent}^",
+            self.text
+        )
+    }
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+pub fn parse_error(path: &Path, source: &str, error: Error) -> Error {
+    let located = |span: Option<Range<usize>>, message: &str| match span {
+        Some(span) => SourceLocation::new(path, source, span.start).error(message),
+        None => anyhow!("{}: {message}", path.display()),
+    };
+    if let Some(error) = error.downcast_ref::<toml::de::Error>() {
+        return located(error.span(), error.message());
+    }
+    if let Some(error) = error.downcast_ref::<toml_edit::TomlError>() {
+        return located(error.span(), error.message());
+    }
+    error
+}
+
+pub fn combine(mut errors: Vec<Error>) -> Error {
+    match errors.len() {
+        1 => errors.remove(0),
+        count => {
+            let messages: Vec<String> = errors.iter().map(|error| format!("{error:#}")).collect();
+            anyhow!("{count} errors found\n\n{}", messages.join("\n\n"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "[settings]\nendpoint = \"http://localhost\"\n\n[[test-case]]\ndescription = \"Pāli\"\nqury = \"metta\"\n";
+
+    #[test]
+    fn line_and_column() {
+        let offset = SOURCE.find("qury").unwrap();
+        let location = SourceLocation::new(Path::new("suite.toml"), SOURCE, offset);
+        assert_eq!(location.to_string(), "suite.toml:6:1");
+
+        let offset = SOURCE.find("li\"").unwrap();
+        let location = SourceLocation::new(Path::new("suite.toml"), SOURCE, offset);
+        assert_eq!(location.to_string(), "suite.toml:5:18");
+    }
+
+    #[test]
+    fn error_has_snippet() {
+        let offset = SOURCE.find("localhost").unwrap();
+        let error = SourceLocation::new(Path::new("suite.toml"), SOURCE, offset)
+            .error("Something is wrong");
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:2:20: Something is wrong\n  |\n2 | endpoint = \"http://localhost\"\n  |                    ^"
+        );
+    }
+
+    #[test]
+    fn toml_parse_error_is_located() {
+        let source = "[settings]\nendpoint = [\n";
+        let error = Error::from(source.parse::<toml::Table>().unwrap_err());
+        let located = parse_error(Path::new("suite.toml"), source, error);
+        assert!(
+            located
+                .to_string()
+                .starts_with("suite.toml:2:13: unclosed array, expected `]`"),
+            "{located}"
+        );
+    }
+
+    #[test]
+    fn combine_errors() {
+        assert_eq!(combine(vec![anyhow!("First")]).to_string(), "First");
+        assert_eq!(
+            combine(vec![anyhow!("First"), anyhow!("Second")]).to_string(),
+            "2 errors found\n\nFirst\n\nSecond"
+        );
+    }
+}
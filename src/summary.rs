@@ -1,7 +1,9 @@
 use crate::outcome::Outcome;
 use crate::rank::Rank;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Summary {
     Error,
     Passed,
@@ -11,7 +13,7 @@ pub enum Summary {
 impl From<&Outcome> for Summary {
     fn from(outcome: &Outcome) -> Self {
         match outcome {
-            Outcome::Error { message: _ } => Summary::Error,
+            Outcome::Error { .. } => Summary::Error,
             Outcome::Success => Summary::Passed,
             Outcome::Found { results: _ } => Summary::Passed,
             Outcome::NotFound { results: _ } => Summary::Failed,
@@ -34,11 +36,13 @@ impl From<&Outcome> for Summary {
 mod tests {
     use super::*;
     use crate::identifiers::SuttaplexUid;
+    use crate::search_error::ErrorKind;
     use crate::search_results::SearchResults;
 
     #[test]
     fn summary_error_is_error() {
         let outcome = Outcome::Error {
+            kind: ErrorKind::Transport,
             message: String::from("An error occured"),
         };
         assert_eq!(Summary::from(&outcome), Summary::Error);
@@ -0,0 +1,209 @@
+use crate::reporter::Reporter;
+use crate::result_count::ResultCount;
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use crate::test_suite::TestSuite;
+use anyhow::Result;
+use std::io::Write;
+use std::path::Path;
+
+pub struct TapReporter<W: Write> {
+    writer: W,
+    number: usize,
+}
+
+impl<W: Write> TapReporter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, number: 0 }
+    }
+
+    fn comment(&mut self, text: &str) -> Result<()> {
+        for line in text.trim_end().lines() {
+            match line.is_empty() {
+                true => writeln!(self.writer, "#")?,
+                false => writeln!(self.writer, "# {line}")?,
+            }
+        }
+        Ok(())
+    }
+
+    fn description(result: &TestResult) -> String {
+        result
+            .test_case
+            .description
+            .replace('\\', "\\\\")
+            .replace('#', "\\#")
+    }
+
+    fn diagnostics(&mut self, result: &TestResult, summary: &Summary) -> Result<()> {
+        writeln!(self.writer, "  ---")?;
+        if let Some(detail) = result.detail_line() {
+            writeln!(
+                self.writer,
+                "  message: {}",
+                serde_json::to_string(&detail)?
+            )?;
+        }
+        match summary {
+            Summary::Passed => {}
+            Summary::Failed => writeln!(self.writer, "  severity: fail")?,
+            Summary::Error => writeln!(self.writer, "  severity: error")?,
+        }
+        writeln!(self.writer, "  elapsed-ms: {}", result.elapsed.as_millis())?;
+        writeln!(self.writer, "  ...")?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Reporter for TapReporter<W> {
+    fn begin(&mut self, _suite_path: &Path, test_suite: &TestSuite, total: usize) -> Result<()> {
+        writeln!(self.writer, "TAP version 14")?;
+        writeln!(self.writer, "1..{total}")?;
+        self.comment(test_suite.headline().as_str())
+    }
+
+    fn result(&mut self, result: &TestResult) -> Result<()> {
+        self.number += 1;
+        let summary = Summary::from(&result.outcome);
+        let status = match summary {
+            Summary::Passed => "ok",
+            Summary::Failed | Summary::Error => "not ok",
+        };
+        writeln!(
+            self.writer,
+            "{status} {} - {}",
+            self.number,
+            Self::description(result)
+        )?;
+        self.diagnostics(result, &summary)
+    }
+
+    fn note(&mut self, note: &str) -> Result<()> {
+        self.comment(note)
+    }
+
+    fn info(&mut self, info: &str) -> Result<()> {
+        self.comment(info)
+    }
+
+    fn finish(&mut self, count: &ResultCount) -> Result<()> {
+        self.comment(count.to_string().as_str())
+    }
+
+    fn total(&mut self, count: &ResultCount) -> Result<()> {
+        self.comment(format!("All suites: {count}").as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::search_error::ErrorKind;
+    use crate::search_results::SearchResults;
+    use crate::test_fixtures::test_case;
+    use std::time::Duration;
+
+    fn suite() -> TestSuite {
+        TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Search is successful"
+            query = "dhamma"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn tap_report() {
+        let mut output = Vec::new();
+        let mut count = ResultCount::new();
+        count.add(&Outcome::Success);
+        count.add(&Outcome::NotFound {
+            results: SearchResults::Suttaplex {
+                expected: SuttaplexUid::from("mn1"),
+                results: vec![],
+            },
+        });
+        count.add(&Outcome::Error {
+            kind: ErrorKind::HttpStatus,
+            message: String::from("Bad Gateway"),
+        });
+
+        let mut reporter = TapReporter::new(&mut output);
+        reporter
+            .begin(Path::new("suite.toml"), &suite(), 3)
+            .unwrap();
+        reporter
+            .result(&TestResult {
+                test_case: test_case("Search is successful"),
+                elapsed: Duration::from_millis(679),
+                outcome: Outcome::Success,
+                body: None,
+            })
+            .unwrap();
+        reporter
+            .result(&TestResult {
+                test_case: test_case("Find suttaplex #1"),
+                elapsed: Duration::from_millis(21),
+                outcome: Outcome::NotFound {
+                    results: SearchResults::Suttaplex {
+                        expected: SuttaplexUid::from("mn1"),
+                        results: vec![],
+                    },
+                },
+                body: None,
+            })
+            .unwrap();
+        reporter
+            .result(&TestResult {
+                test_case: test_case("Causes internal server error"),
+                elapsed: Duration::from_millis(55),
+                outcome: Outcome::Error {
+                    kind: ErrorKind::HttpStatus,
+                    message: String::from(
+                        "Expected status code to be 200 OK but got 502 \"Bad Gateway\"",
+                    ),
+                },
+                body: None,
+            })
+            .unwrap();
+        reporter
+            .note("Compared with baseline\n\nNewly failing")
+            .unwrap();
+        reporter.finish(&count).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"TAP version 14
+1..3
+# Running tests against endpoint http://localhost/api/search/instant with 0ms delay
+ok 1 - Search is successful
+  ---
+  elapsed-ms: 679
+  ...
+not ok 2 - Find suttaplex \#1
+  ---
+  message: "Suttaplex hit mn1 not found in search results"
+  severity: fail
+  elapsed-ms: 21
+  ...
+not ok 3 - Causes internal server error
+  ---
+  message: "Expected status code to be 200 OK but got 502 \"Bad Gateway\""
+  severity: error
+  elapsed-ms: 55
+  ...
+# Compared with baseline
+#
+# Newly failing
+# 1 passed, 1 failed, 1 encountered an error (0 transport, 1 HTTP status, 0 parse)
+"#
+        );
+    }
+}
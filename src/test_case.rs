@@ -1,8 +1,17 @@
 use crate::defaults::Defaults;
 use crate::expected::{Expected, ExpectedDetails};
+use crate::group::Group;
 use crate::test_suite::TestCaseDetails;
 use anyhow::{Context, Result};
 
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Origin {
+    #[default]
+    Suite,
+    Matrix,
+    TestTable,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TestCase {
     pub query: String,
@@ -13,6 +22,9 @@ pub struct TestCase {
     pub selected_languages: Vec<String>,
     pub match_partial: bool,
     pub expected: Option<Expected>,
+    pub tags: Vec<String>,
+    pub group: Option<String>,
+    pub origin: Origin,
 }
 
 impl TestCase {
@@ -55,6 +67,8 @@ impl TestCase {
             .clone()
             .unwrap();
 
+        let tags = Self::tags(&defaults.tags, &provided.tags);
+
         let expected = Self::expected(&provided.expected)
             .context(Self::expected_error_message(&description))?;
 
@@ -67,9 +81,34 @@ impl TestCase {
             limit,
             restrict,
             expected,
+            tags,
+            group: None,
+            origin: provided.origin.clone(),
+        })
+    }
+
+    pub fn in_group(
+        group: &Group,
+        defaults: &Defaults,
+        provided: &TestCaseDetails,
+    ) -> Result<TestCase> {
+        let test_case = Self::new(&group.defaults.or(defaults), provided)?;
+        Ok(TestCase {
+            group: Some(group.name.clone()),
+            ..test_case
         })
     }
 
+    fn tags(defaults: &[String], provided: &[String]) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in defaults.iter().chain(provided) {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        tags
+    }
+
     fn missing_message(description: &str, key: &str) -> String {
         format!("Test case `{description}` missing `{key}` and no default provided.")
     }
@@ -92,8 +131,10 @@ impl TestCase {
 #[cfg(test)]
 mod tests {
     use crate::defaults::Defaults;
-    use crate::test_case::TestCase;
+    use crate::group::Group;
+    use crate::test_case::{Origin, TestCase};
     use crate::test_suite::TestCaseDetails;
+    use std::collections::BTreeMap;
 
     fn all_details_but_expected() -> TestCaseDetails {
         TestCaseDetails {
@@ -105,6 +146,10 @@ mod tests {
             limit: Some(50),
             restrict: Some("all".to_string()),
             expected: None,
+            tags: Vec::new(),
+            matrix: BTreeMap::new(),
+            location: None,
+            origin: Origin::Suite,
         }
     }
 
@@ -115,6 +160,7 @@ mod tests {
             restrict: Some("all".to_string()),
             selected_languages: Some(vec!["en".to_string(), "pli".to_string()]),
             match_partial: Some(false),
+            tags: Vec::new(),
         }
     }
 
@@ -128,6 +174,9 @@ mod tests {
             limit: 50,
             restrict: "all".to_string(),
             expected: None,
+            tags: Vec::new(),
+            group: None,
+            origin: Origin::Suite,
         }
     }
 
@@ -142,6 +191,10 @@ mod tests {
             limit: None,
             restrict: None,
             expected: None,
+            tags: Vec::new(),
+            matrix: BTreeMap::new(),
+            location: None,
+            origin: Origin::Suite,
         };
 
         let actual = TestCase::new(&defaults(), &details).unwrap();
@@ -171,6 +224,10 @@ mod tests {
             limit: Some(50),
             restrict: Some("all".to_string()),
             expected: None,
+            tags: Vec::new(),
+            matrix: BTreeMap::new(),
+            location: None,
+            origin: Origin::Suite,
         };
 
         if let Err(error) = TestCase::new(&defaults, &details) {
@@ -255,4 +312,44 @@ mod tests {
             "Test case `Search in English only.` missing `restrict` and no default provided."
         );
     }
+
+    #[test]
+    fn tags_are_union_of_defaults_and_provided() {
+        let defaults = Defaults {
+            tags: vec!["smoke".to_string(), "pali".to_string()],
+            ..defaults()
+        };
+        let details = TestCaseDetails {
+            tags: vec!["volpage".to_string(), "smoke".to_string()],
+            ..all_details_but_expected()
+        };
+
+        let actual = TestCase::new(&defaults, &details).unwrap();
+
+        assert_eq!(actual.tags, vec!["smoke", "pali", "volpage"]);
+    }
+
+    #[test]
+    fn group_defaults_between_test_and_suite_defaults() {
+        let group = Group {
+            name: "German".to_string(),
+            defaults: Defaults {
+                selected_languages: Some(vec!["de".to_string()]),
+                limit: Some(20),
+                ..Defaults::default()
+            },
+            test_details: Vec::new(),
+        };
+        let details = TestCaseDetails {
+            limit: Some(30),
+            selected_languages: None,
+            ..all_details_but_expected()
+        };
+
+        let actual = TestCase::in_group(&group, &defaults(), &details).unwrap();
+
+        assert_eq!(actual.selected_languages, vec!["de"]);
+        assert_eq!(actual.limit, 30);
+        assert_eq!(actual.group, Some("German".to_string()));
+    }
 }
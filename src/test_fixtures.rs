@@ -0,0 +1,17 @@
+use crate::test_case::{Origin, TestCase};
+
+pub fn test_case(description: &str) -> TestCase {
+    TestCase {
+        description: String::from(description),
+        query: String::from("query"),
+        limit: 10,
+        site_language: String::from("en"),
+        restrict: String::from("all"),
+        selected_languages: vec![String::from("en")],
+        match_partial: false,
+        expected: None,
+        tags: Vec::new(),
+        group: None,
+        origin: Origin::Suite,
+    }
+}
@@ -0,0 +1,110 @@
+use crate::summary::Summary;
+use crate::test_result::TestResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TestRecord {
+    pub description: String,
+    pub summary: Summary,
+    pub rank: Option<usize>,
+    pub top: Vec<String>,
+    pub elapsed_ms: u128,
+}
+
+impl From<&TestResult> for TestRecord {
+    fn from(result: &TestResult) -> Self {
+        let search_results = result.outcome.results();
+        Self {
+            description: result.test_case.description.clone(),
+            summary: Summary::from(&result.outcome),
+            rank: search_results.and_then(|results| results.rank()),
+            top: search_results
+                .map(|results| results.ids())
+                .unwrap_or_default(),
+            elapsed_ms: result.elapsed.as_millis(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::SuttaplexUid;
+    use crate::outcome::Outcome;
+    use crate::rank::Rank;
+    use crate::search_error::ErrorKind;
+    use crate::search_results::SearchResults;
+    use crate::test_fixtures::test_case;
+    use std::time::Duration;
+
+    #[test]
+    fn record_from_ranked_result() {
+        let result = TestResult {
+            test_case: test_case("Expecting top rank"),
+            elapsed: Duration::from_millis(76),
+            outcome: Outcome::Ranked {
+                results: SearchResults::Suttaplex {
+                    expected: SuttaplexUid::from("mn2"),
+                    results: vec![SuttaplexUid::from("mn1"), SuttaplexUid::from("mn2")],
+                },
+                rank: Rank::TooLow {
+                    minimum: 1,
+                    actual: 2,
+                },
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            TestRecord::from(&result),
+            TestRecord {
+                description: String::from("Expecting top rank"),
+                summary: Summary::Failed,
+                rank: Some(2),
+                top: vec![String::from("mn1"), String::from("mn2")],
+                elapsed_ms: 76,
+            }
+        );
+    }
+
+    #[test]
+    fn record_from_error() {
+        let result = TestResult {
+            test_case: test_case("Something will go wrong"),
+            elapsed: Duration::from_millis(4321),
+            outcome: Outcome::Error {
+                kind: ErrorKind::HttpStatus,
+                message: String::from("Something went wrong"),
+            },
+            body: None,
+        };
+
+        assert_eq!(
+            TestRecord::from(&result),
+            TestRecord {
+                description: String::from("Something will go wrong"),
+                summary: Summary::Error,
+                rank: None,
+                top: Vec::new(),
+                elapsed_ms: 4321,
+            }
+        );
+    }
+
+    #[test]
+    fn serialises_to_json() {
+        let record = TestRecord {
+            description: String::from("Find mn1"),
+            summary: Summary::Passed,
+            rank: Some(1),
+            top: vec![String::from("mn1")],
+            elapsed_ms: 12,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"description":"Find mn1","summary":"passed","rank":1,"top":["mn1"],"elapsed-ms":12}"#
+        );
+    }
+}
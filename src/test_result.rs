@@ -5,17 +5,19 @@ use std::time::Duration;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct TestResult {
-    pub description: String,
+    pub test_case: TestCase,
     pub elapsed: Duration,
     pub outcome: Outcome,
+    pub body: Option<String>,
 }
 
 impl TestResult {
     pub fn new(test_case: &TestCase, response: TimedResponse) -> Self {
         Self {
-            description: test_case.description.clone(),
+            test_case: test_case.clone(),
             elapsed: response.elapsed,
             outcome: Outcome::new(&test_case.expected, response.json),
+            body: response.body,
         }
     }
 }
@@ -23,6 +25,7 @@ impl TestResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_case::Origin;
     use crate::test_json::SUTTAPLEX_MN1_JSON;
 
     fn test_case() -> TestCase {
@@ -35,6 +38,9 @@ mod tests {
             limit: 50,
             restrict: "all".to_string(),
             expected: None,
+            tags: Vec::new(),
+            group: None,
+            origin: Origin::Suite,
         }
     }
 
@@ -42,6 +48,7 @@ mod tests {
         TimedResponse {
             elapsed: Duration::from_secs(3),
             json: Ok(String::from(SUTTAPLEX_MN1_JSON)),
+            body: None,
         }
     }
 
@@ -53,7 +60,7 @@ mod tests {
         };
 
         let test_result = TestResult::new(&test_case, ok_response());
-        assert_eq!(test_result.description, "Matching description");
+        assert_eq!(test_result.test_case.description, "Matching description");
     }
 
     #[test]
@@ -1,15 +1,30 @@
 use crate::defaults::Defaults;
+use crate::environment::Environment;
 use crate::expected::ExpectedDetails;
-use crate::test_case::TestCase;
-use anyhow::{Context, Result};
+use crate::group::Group;
+use crate::include::Included;
+use crate::matrix::expand;
+use crate::overrides::{Override, apply_overrides};
+use crate::source_location::{SourceLocation, combine, parse_error};
+use crate::test_case::{Origin, TestCase};
+use crate::test_table::TestTable;
+use anyhow::{Error, Result, anyhow};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use toml::{Spanned, Table, Value};
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 struct Settings {
-    endpoint: String,
+    endpoint: Option<String>,
+    compare_endpoint: Option<String>,
     #[serde(default)]
     delay: u64,
+    #[serde(default)]
+    environments: BTreeMap<String, Environment>,
+    default_environment: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq)]
@@ -23,43 +38,362 @@ pub struct TestCaseDetails {
     pub selected_languages: Option<Vec<String>>,
     pub match_partial: Option<bool>,
     pub expected: Option<ExpectedDetails>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub matrix: BTreeMap<String, Vec<toml::Value>>,
+    #[serde(skip)]
+    pub location: Option<SourceLocation>,
+    #[serde(skip)]
+    pub origin: Origin,
+}
+
+impl TestCaseDetails {
+    pub fn locate(&self, error: Error) -> Error {
+        match &self.location {
+            Some(location) => location.error(format!("{error:#}")),
+            None => error,
+        }
+    }
+}
+
+type RawTestCase = Spanned<BTreeMap<Spanned<String>, Value>>;
+
+#[derive(Deserialize)]
+struct RawSuite {
+    #[serde(default, rename = "test-case")]
+    test_details: Vec<RawTestCase>,
+    #[serde(default, rename = "group")]
+    groups: Vec<RawGroup>,
+}
+
+#[derive(Deserialize)]
+struct RawSettings {
+    settings: Spanned<RawSettingsTable>,
+}
+
+#[derive(Deserialize)]
+struct RawSettingsTable {
+    #[serde(rename = "default-environment")]
+    default_environment: Option<Spanned<String>>,
+}
+
+#[derive(Deserialize)]
+struct RawGroup {
+    #[serde(default, rename = "test-case")]
+    test_details: Vec<RawTestCase>,
+}
+
+impl RawSuite {
+    fn parse(source: &str) -> Vec<RawTestCase> {
+        match toml::from_str::<RawSuite>(source) {
+            Ok(raw) => {
+                let grouped = raw.groups.into_iter().flat_map(|group| group.test_details);
+                raw.test_details.into_iter().chain(grouped).collect()
+            }
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+pub fn parse_located<T: DeserializeOwned>(
+    path: &Path,
+    source: &str,
+) -> Result<(T, Vec<SourceLocation>)> {
+    let test_cases = RawSuite::parse(source);
+    match toml::from_str::<T>(source) {
+        Ok(parsed) => Ok((parsed, test_case_locations(path, source))),
+        Err(error) => {
+            let reported_at = error.span().map(|span| span.start).unwrap_or_default();
+            let mut errors = vec![(
+                reported_at,
+                parse_error(path, source, Error::from(error.clone())),
+            )];
+            errors.extend(test_case_errors(path, source, &test_cases, &error));
+            errors.sort_by_key(|(offset, _)| *offset);
+            Err(combine(
+                errors.into_iter().map(|(_, error)| error).collect(),
+            ))
+        }
+    }
+}
+
+fn test_case_locations(path: &Path, source: &str) -> Vec<SourceLocation> {
+    RawSuite::parse(source)
+        .iter()
+        .map(|test_case| SourceLocation::new(path, source, test_case.span().start))
+        .collect()
+}
+
+fn test_case_errors(
+    path: &Path,
+    source: &str,
+    test_cases: &[RawTestCase],
+    reported: &toml::de::Error,
+) -> Vec<(usize, Error)> {
+    let mut starts: Vec<usize> = test_cases
+        .iter()
+        .map(|test_case| test_case.span().start)
+        .collect();
+    starts.sort();
+    let reported_at = reported.span().map(|span| span.start);
+    test_cases
+        .iter()
+        .filter_map(|test_case| {
+            let table: Table = test_case
+                .get_ref()
+                .iter()
+                .map(|(key, value)| (key.get_ref().clone(), value.clone()))
+                .collect();
+            let error = Value::Table(table).try_into::<TestCaseDetails>().err()?;
+            let start = test_case.span().start;
+            let end = starts
+                .iter()
+                .find(|next| **next > start)
+                .copied()
+                .unwrap_or(source.len());
+            let already_reported = error.message() == reported.message()
+                && reported_at.is_some_and(|at| (start..end).contains(&at));
+            if already_reported {
+                return None;
+            }
+            let offset = unknown_key(test_case, error.message()).unwrap_or(start);
+            let location = SourceLocation::new(path, source, offset);
+            Some((offset, location.error(error.message())))
+        })
+        .collect()
+}
+
+fn unknown_key(test_case: &RawTestCase, message: &str) -> Option<usize> {
+    let name = message.strip_prefix("unknown field `")?.split('`').next()?;
+    test_case
+        .get_ref()
+        .keys()
+        .find(|key| key.get_ref() == name)
+        .map(|key| key.span().start)
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct TestSuite {
+    #[serde(default)]
+    include: Vec<PathBuf>,
     settings: Settings,
     #[serde(default)]
     defaults: Defaults,
-    #[serde[rename = "test-case"]]
+    #[serde(default, rename = "test-case")]
     test_details: Vec<TestCaseDetails>,
+    #[serde(default, rename = "group")]
+    groups: Vec<Group>,
+    #[serde(default, rename = "test-table")]
+    test_tables: Vec<TestTable>,
+    #[serde(skip)]
+    environment_name: Option<String>,
+    #[serde(skip)]
+    endpoint_overridden: bool,
+    #[serde(skip)]
+    settings_location: Option<SourceLocation>,
+    #[serde(skip)]
+    default_environment_location: Option<SourceLocation>,
+}
+
+fn locate(location: &Option<SourceLocation>, error: Error) -> Error {
+    match location {
+        Some(location) => location.error(format!("{error:#}")),
+        None => error,
+    }
 }
 
 impl TestSuite {
+    #[cfg(test)]
     pub fn load_from_string(source: &str) -> Result<TestSuite> {
-        toml::from_str::<TestSuite>(source).context("Failed to parse TOML.")
+        Self::load(Path::new("suite.toml"), source, &[])
+    }
+
+    pub fn load(path: &Path, source: &str, overrides: &[Override]) -> Result<TestSuite> {
+        let overridden = apply_overrides(source, overrides, |name| std::env::var(name).ok())
+            .map_err(|error| parse_error(path, source, error))?;
+        let mut suite = Self::parse(path, source, overridden.as_str())?;
+        suite.endpoint_overridden = overrides
+            .iter()
+            .any(|setting| setting.sets("settings.endpoint"));
+        if let Some(name) = suite.settings.default_environment.clone() {
+            suite
+                .choose_environment(name.as_str())
+                .map_err(|error| locate(&suite.default_environment_location, error))?;
+        }
+        Ok(suite)
+    }
+
+    pub fn check_endpoint(&self) -> Result<()> {
+        match self.named_environment().is_none() && self.settings.endpoint.is_none() {
+            true => Err(locate(
+                &self.settings_location,
+                anyhow!(
+                    "Settings must have an `endpoint` or a `default-environment`, or an environment must be chosen with `--env`"
+                ),
+            )),
+            false => Ok(()),
+        }
+    }
+
+    fn parse(path: &Path, source: &str, overridden: &str) -> Result<TestSuite> {
+        let mut suite = match toml::from_str::<TestSuite>(overridden) {
+            Ok(suite) => suite,
+            Err(error) => {
+                parse_located::<TestSuite>(path, source)?;
+                return Err(anyhow!(
+                    "{}: {} (after applying `--set` and environment variables)",
+                    path.display(),
+                    error.message()
+                ));
+            }
+        };
+        let locations = test_case_locations(path, source);
+        let details = suite.test_details.iter_mut().chain(
+            suite
+                .groups
+                .iter_mut()
+                .flat_map(|group| &mut group.test_details),
+        );
+        for (details, location) in details.zip(locations) {
+            details.location = Some(location);
+        }
+        if let Ok(raw) = toml::from_str::<RawSettings>(source) {
+            let located = |offset| Some(SourceLocation::new(path, source, offset));
+            suite.settings_location = located(raw.settings.span().start);
+            suite.default_environment_location = raw
+                .settings
+                .get_ref()
+                .default_environment
+                .as_ref()
+                .and_then(|name| located(name.span().start));
+        }
+        Ok(suite)
+    }
+
+    pub fn select_environment(&mut self, name: &str) -> Result<()> {
+        self.choose_environment(name)
+            .map_err(|error| locate(&self.settings_location, error))
+    }
+
+    fn choose_environment(&mut self, name: &str) -> Result<()> {
+        if !self.settings.environments.contains_key(name) {
+            return Err(anyhow!("No environment named `{name}` in settings"));
+        }
+        self.environment_name = Some(String::from(name));
+        Ok(())
+    }
+
+    pub fn includes(&self) -> Vec<PathBuf> {
+        self.include.clone()
+    }
+
+    pub fn add_included(&mut self, included: Included) {
+        self.defaults = self.defaults.or(&included.defaults);
+        self.test_details.splice(0..0, included.test_details);
+    }
+
+    pub fn test_tables(&self) -> Vec<TestTable> {
+        self.test_tables.clone()
+    }
+
+    pub fn add_test_details(&mut self, test_details: Vec<TestCaseDetails>) {
+        self.test_details.extend(test_details);
+    }
+
+    pub fn environment_name(&self) -> Option<String> {
+        self.environment_name.clone()
+    }
+
+    fn named_environment(&self) -> Option<&Environment> {
+        self.environment_name
+            .as_ref()
+            .and_then(|name| self.settings.environments.get(name))
+    }
+
+    pub fn environment(&self) -> Environment {
+        let environment = self.named_environment().cloned().unwrap_or_else(|| {
+            Environment::new(self.settings.endpoint.clone().unwrap_or_default())
+        });
+        let endpoint = match (&self.settings.endpoint, self.endpoint_overridden) {
+            (Some(endpoint), true) => endpoint.clone(),
+            _ => environment.endpoint.clone(),
+        };
+        Environment {
+            endpoint,
+            delay: Some(self.delay()),
+            ..environment
+        }
     }
 
     pub fn endpoint(&self) -> String {
-        self.settings.endpoint.clone()
+        self.environment().endpoint
+    }
+
+    pub fn compare_endpoint(&self) -> Option<String> {
+        self.settings.compare_endpoint.clone()
     }
 
     pub fn delay(&self) -> u64 {
-        self.settings.delay
+        self.named_environment()
+            .and_then(|environment| environment.delay)
+            .unwrap_or(self.settings.delay)
+    }
+
+    pub fn test_count(&self) -> usize {
+        self.test_cases().count()
     }
 
     pub fn test_cases(&self) -> impl Iterator<Item = Result<TestCase>> {
-        self.test_details
-            .iter()
-            .map(|details| TestCase::new(&self.defaults, details))
+        let ungrouped = self.test_details.iter().flat_map(|details| {
+            Self::expanded(details, |expanded| TestCase::new(&self.defaults, expanded))
+        });
+        let grouped = self.groups.iter().flat_map(|group| {
+            group.test_details.iter().flat_map(|details| {
+                Self::expanded(details, |expanded| {
+                    TestCase::in_group(group, &self.defaults, expanded)
+                })
+            })
+        });
+        ungrouped.chain(grouped)
+    }
+
+    fn expanded(
+        details: &TestCaseDetails,
+        resolve: impl Fn(&TestCaseDetails) -> Result<TestCase>,
+    ) -> Vec<Result<TestCase>> {
+        match expand(details) {
+            Ok(expanded) => expanded
+                .iter()
+                .map(|expanded| resolve(expanded).map_err(|error| details.locate(error)))
+                .collect(),
+            Err(error) => vec![Err(details.locate(error))],
+        }
     }
 
     pub fn headline(&self) -> String {
-        format!(
-            "Running tests against endpoint {} with {}ms delay",
-            self.endpoint(),
-            self.delay()
-        )
+        let headline = self.endpoint_headline();
+        match self.environment_name() {
+            Some(name) => format!("{headline} in environment {name}"),
+            None => headline,
+        }
+    }
+
+    fn endpoint_headline(&self) -> String {
+        match self.compare_endpoint() {
+            Some(compare_endpoint) => format!(
+                "Comparing endpoint {} with endpoint {} with {}ms delay",
+                self.endpoint(),
+                compare_endpoint,
+                self.delay()
+            ),
+            None => format!(
+                "Running tests against endpoint {} with {}ms delay",
+                self.endpoint(),
+                self.delay()
+            ),
+        }
     }
 }
 
@@ -93,9 +427,13 @@ mod tests {
         .unwrap();
 
         let expected = TestSuite {
+            include: Vec::new(),
             settings: Settings {
-                endpoint: "http://localhost/api/search/instant".to_string(),
+                endpoint: Some("http://localhost/api/search/instant".to_string()),
+                compare_endpoint: None,
                 delay: 3000,
+                environments: BTreeMap::new(),
+                default_environment: None,
             },
             defaults: Defaults {
                 limit: Some(50),
@@ -103,6 +441,7 @@ mod tests {
                 restrict: Some("all".to_string()),
                 selected_languages: Some(vec!["en".to_string(), "pli".to_string()]),
                 match_partial: Some(false),
+                tags: Vec::new(),
             },
             test_details: vec![TestCaseDetails {
                 description: "Search for the metta sutta in English and Pali".to_string(),
@@ -113,16 +452,36 @@ mod tests {
                 restrict: None,
                 match_partial: None,
                 expected: None,
+                tags: Vec::new(),
+                matrix: BTreeMap::new(),
+                location: suite.test_details[0].location.clone(),
+                origin: Origin::Suite,
             }],
+            groups: Vec::new(),
+            test_tables: Vec::new(),
+            environment_name: None,
+            endpoint_overridden: false,
+            settings_location: suite.settings_location.clone(),
+            default_environment_location: None,
         };
 
         assert_eq!(suite, expected);
+        assert_eq!(
+            suite.test_details[0]
+                .location
+                .as_ref()
+                .map(|location| location.to_string()),
+            Some(String::from("suite.toml:13:13"))
+        );
     }
 
     #[test]
     fn error_when_not_valid_toml() {
         let error = TestSuite::load_from_string("This is not TOML").unwrap_err();
-        assert_eq!(error.to_string(), "Failed to parse TOML.")
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:1:6: key with no value, expected `=`\n  |\n1 | This is not TOML\n  |      ^"
+        )
     }
 
     #[test]
@@ -166,7 +525,10 @@ mod tests {
         "#,
         )
         .unwrap_err();
-        assert_eq!(error.to_string(), "Failed to parse TOML.");
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:6:13: missing field `description`\n  |\n6 |             [[test-case]]\n  |             ^"
+        );
     }
 
     #[test]
@@ -187,7 +549,45 @@ mod tests {
         "#,
         )
         .unwrap_err();
-        assert_eq!(error.to_string(), "Failed to parse TOML.");
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:6:13: missing field `query`\n  |\n6 |             [[test-case]]\n  |             ^"
+        );
+    }
+
+    #[test]
+    fn all_test_case_errors_are_reported() {
+        let error = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Misspelt key"
+            qury = "metta"
+
+            [[test-case]]
+            description = "Fine"
+            query = "metta"
+
+            [[group]]
+            name = "Dictionary"
+
+            [[group.test-case]]
+            query = "dosa"
+        "#,
+        )
+        .unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("2 errors found\n\n"), "{message}");
+        assert!(
+            message.contains("suite.toml:7:13: unknown field `qury`"),
+            "{message}"
+        );
+        assert!(
+            message.contains("suite.toml:16:13: missing field `description`"),
+            "{message}"
+        );
     }
 
     #[test]
@@ -225,6 +625,44 @@ mod tests {
         assert_eq!(queries, vec!("metta", "pacch"));
     }
 
+    #[test]
+    fn tags_provided_in_defaults_and_test_cases() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            limit = 50
+            site-language = "en"
+            restrict = "all"
+            selected-languages = ["en", "pli"]
+            match-partial = false
+            tags = ["nightly"]
+
+            [[test-case]]
+            description = "Search for the metta sutta in English and Pali"
+            query = "metta"
+            tags = ["smoke", "pali"]
+
+            [[test-case]]
+            description = "Has multiple dictionary results."
+            query = "pacch"
+        "#,
+        )
+        .unwrap();
+
+        let tags: Vec<_> = suite
+            .test_cases()
+            .map(|test_case| test_case.unwrap().tags)
+            .collect();
+
+        assert_eq!(
+            tags,
+            vec!(vec!["nightly", "smoke", "pali"], vec!["nightly"])
+        );
+    }
+
     #[test]
     fn suite_provides_global_settings() {
         let suite = TestSuite::load_from_string(
@@ -355,6 +793,45 @@ mod tests {
         )
     }
 
+    #[test]
+    fn compare_endpoint_defaults_to_none() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Search for the metta sutta in English and Pali"
+            query = "metta"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(suite.compare_endpoint(), None);
+    }
+
+    #[test]
+    fn format_headline_with_compare_endpoint() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "https://suttacentral.net/api/search/instant"
+            compare-endpoint = "https://staging.suttacentral.net/api/search/instant"
+            delay = 100
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            suite.headline(),
+            "Comparing endpoint https://suttacentral.net/api/search/instant with endpoint https://staging.suttacentral.net/api/search/instant with 100ms delay"
+        )
+    }
+
     #[test]
     fn two_expected_type_gives_meaningful_error_message() {
         let suite = TestSuite::load_from_string(
@@ -383,7 +860,400 @@ mod tests {
 
         assert_eq!(
             format!("{error:#}"),
-            "Test case `Has two types expected`: more than one expected result provided"
+            "suite.toml:13:13: Test case `Has two types expected`: more than one expected result provided\n   |\n13 |             [[test-case]]\n   |             ^"
+        );
+    }
+
+    fn suite_with_environments() -> TestSuite {
+        TestSuite::load_from_string(
+            r#"
+            [settings]
+            delay = 10
+            default-environment = "local"
+
+            [settings.environments.local]
+            endpoint = "http://localhost/api/search/instant"
+
+            [settings.environments.staging]
+            endpoint = "https://staging.suttacentral.net/api/search/instant"
+            delay = 100
+            timeout = 5000
+            headers = { Authorization = "Basic c3RhZ2luZw==" }
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn default_environment_is_selected() {
+        let suite = suite_with_environments();
+        assert_eq!(suite.environment_name(), Some(String::from("local")));
+        assert_eq!(suite.endpoint(), "http://localhost/api/search/instant");
+        assert_eq!(suite.delay(), 10);
+        assert_eq!(suite.environment().timeout, None);
+    }
+
+    #[test]
+    fn select_named_environment() {
+        let mut suite = suite_with_environments();
+        suite.select_environment("staging").unwrap();
+        let environment = suite.environment();
+        assert_eq!(
+            environment.endpoint,
+            "https://staging.suttacentral.net/api/search/instant"
+        );
+        assert_eq!(environment.delay, Some(100));
+        assert_eq!(environment.timeout, Some(5000));
+        assert_eq!(environment.headers.len(), 1);
+        assert_eq!(
+            suite.headline(),
+            "Running tests against endpoint https://staging.suttacentral.net/api/search/instant with 100ms delay in environment staging"
+        );
+    }
+
+    #[test]
+    fn error_when_environment_unknown() {
+        let mut suite = suite_with_environments();
+        let error = suite.select_environment("production").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:2:13: No environment named `production` in settings\n  |\n2 |             [settings]\n  |             ^"
+        );
+    }
+
+    #[test]
+    fn error_when_default_environment_unknown() {
+        let error = TestSuite::load_from_string(
+            r#"
+            [settings]
+            default-environment = "production"
+
+            [settings.environments.local]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:3:35: No environment named `production` in settings\n  |\n3 |             default-environment = \"production\"\n  |                                   ^"
+        );
+    }
+
+    fn suite_without_default_environment() -> TestSuite {
+        TestSuite::load_from_string(
+            r#"
+            [settings]
+
+            [settings.environments.staging]
+            endpoint = "https://staging.suttacentral.net/api/search/instant"
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn error_when_no_endpoint_or_environment() {
+        let error = suite_without_default_environment()
+            .check_endpoint()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "suite.toml:2:13: Settings must have an `endpoint` or a `default-environment`, or an environment must be chosen with `--env`\n  |\n2 |             [settings]\n  |             ^"
+        );
+    }
+
+    #[test]
+    fn environment_chosen_without_default() {
+        let mut suite = suite_without_default_environment();
+        suite.select_environment("staging").unwrap();
+        suite.check_endpoint().unwrap();
+        assert_eq!(
+            suite.endpoint(),
+            "https://staging.suttacentral.net/api/search/instant"
+        );
+    }
+
+    #[test]
+    fn environment_without_name_uses_settings() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+            delay = 20
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(suite.environment_name(), None);
+        assert_eq!(
+            suite.environment(),
+            Environment {
+                delay: Some(20),
+                ..Environment::new(String::from("http://localhost/api/search/instant"))
+            }
+        );
+    }
+
+    #[test]
+    fn overrides_applied_to_suite() {
+        let overrides: Vec<Override> = vec![
+            "settings.endpoint=https://review-42.suttacentral.net/api/search/instant"
+                .parse()
+                .unwrap(),
+            "defaults.limit=50".parse().unwrap(),
+        ];
+        let suite = TestSuite::load(
+            Path::new("suite.toml"),
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            limit = 10
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+            &overrides,
+        )
+        .unwrap();
+
+        assert_eq!(
+            suite.endpoint(),
+            "https://review-42.suttacentral.net/api/search/instant"
+        );
+        assert_eq!(suite.defaults.limit, Some(50));
+    }
+
+    const MISSPELT_KEYS: &str = r#"[settings]
+endpoint = "http://localhost/api/search/instant"
+environments.local = { endpoint = "${PATH}", delay = "slow" }
+
+[defaults]
+site-language = "en"
+
+[[test-case]]
+description = "Find a sutta"
+query = "metta"
+limti = 10
+"#;
+
+    #[test]
+    fn errors_located_in_file_despite_overrides() {
+        let overrides: Vec<Override> = vec!["defaults.limit=50".parse().unwrap()];
+        let error = TestSuite::load(Path::new("suite.toml"), MISSPELT_KEYS, &overrides)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains(
+                "suite.toml:3:54: invalid type: string \"slow\", expected u64\n  |\n\
+                3 | environments.local = { endpoint = \"${PATH}\", delay = \"slow\" }"
+            ),
+            "{error}"
+        );
+        assert!(
+            error.contains("suite.toml:11:1: unknown field `limti`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn override_errors_are_not_located() {
+        let overrides: Vec<Override> = vec!["settings.timeout-x=1".parse().unwrap()];
+        let error = TestSuite::load(
+            Path::new("suite.toml"),
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+        "#,
+            &overrides,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "suite.toml: unknown field `timeout-x`, expected one of `endpoint`, `compare-endpoint`, `delay`, `environments`, `default-environment` (after applying `--set` and environment variables)"
+        );
+    }
+
+    #[test]
+    fn endpoint_override_wins_over_environment() {
+        let overrides: Vec<Override> = vec![
+            "settings.endpoint=http://localhost:8080/api/search/instant"
+                .parse()
+                .unwrap(),
+        ];
+        let mut suite = TestSuite::load(
+            Path::new("suite.toml"),
+            r#"
+            [settings]
+            default-environment = "local"
+
+            [settings.environments.local]
+            endpoint = "http://localhost/api/search/instant"
+            delay = 50
+
+            [settings.environments.staging]
+            endpoint = "https://staging.suttacentral.net/api/search/instant"
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+            &overrides,
+        )
+        .unwrap();
+
+        assert_eq!(suite.endpoint(), "http://localhost:8080/api/search/instant");
+        assert_eq!(suite.delay(), 50);
+        suite.select_environment("staging").unwrap();
+        assert_eq!(suite.endpoint(), "http://localhost:8080/api/search/instant");
+    }
+
+    #[test]
+    fn environment_wins_over_endpoint_in_file() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost:8080/api/search/instant"
+            default-environment = "local"
+
+            [settings.environments.local]
+            endpoint = "http://localhost/api/search/instant"
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(suite.endpoint(), "http://localhost/api/search/instant");
+    }
+
+    #[test]
+    fn groups_follow_ungrouped_test_cases() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            limit = 10
+            site-language = "en"
+            restrict = "all"
+            selected-languages = ["en", "pli"]
+            match-partial = false
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+
+            [[group]]
+            name = "German"
+            defaults.selected-languages = ["de"]
+
+            [[group.test-case]]
+            description = "Find a German sutta"
+            query = "metta"
+
+            [[group.test-case]]
+            description = "Find a German sutta in English too"
+            query = "metta"
+            selected-languages = ["de", "en"]
+
+            [[group]]
+            name = "Partial"
+            defaults.match-partial = true
+
+            [[group.test-case]]
+            description = "Partial match"
+            query = "mett"
+        "#,
+        )
+        .unwrap();
+
+        let test_cases: Vec<TestCase> = suite
+            .test_cases()
+            .map(|test_case| test_case.unwrap())
+            .collect();
+        let summary: Vec<(Option<&str>, Vec<String>, bool)> = test_cases
+            .iter()
+            .map(|test_case| {
+                (
+                    test_case.group.as_deref(),
+                    test_case.selected_languages.clone(),
+                    test_case.match_partial,
+                )
+            })
+            .collect();
+
+        assert_eq!(suite.test_count(), 4);
+        assert_eq!(
+            summary,
+            vec![
+                (None, vec!["en".to_string(), "pli".to_string()], false),
+                (Some("German"), vec!["de".to_string()], false),
+                (
+                    Some("German"),
+                    vec!["de".to_string(), "en".to_string()],
+                    false
+                ),
+                (
+                    Some("Partial"),
+                    vec!["en".to_string(), "pli".to_string()],
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn matrix_test_cases_are_expanded() {
+        let suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            limit = 10
+            site-language = "en"
+            restrict = "all"
+            selected-languages = ["en", "pli"]
+            match-partial = false
+
+            [[test-case]]
+            description = "Find the root of all things"
+            query = "mn1"
+            matrix = { site-language = ["en", "de"], match-partial = [false, true] }
+            expected.suttaplex = "mn1"
+        "#,
+        )
+        .unwrap();
+
+        let descriptions: Vec<String> = suite
+            .test_cases()
+            .map(|test_case| test_case.unwrap().description)
+            .collect();
+
+        assert_eq!(suite.test_count(), 4);
+        assert_eq!(
+            descriptions[3],
+            "Find the root of all things [match-partial=true, site-language=de]"
         );
     }
 }
@@ -0,0 +1,360 @@
+use crate::source_location::{SourceLocation, combine};
+use crate::test_case::Origin;
+use crate::test_suite::{TestCaseDetails, TestSuite};
+use anyhow::{Context, Result, anyhow};
+use csv::{ReaderBuilder, StringRecord};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+const EXPECTED: [&str; 6] = [
+    "suttaplex",
+    "sutta",
+    "dictionary",
+    "volpage",
+    "other",
+    "min-rank",
+];
+
+const COLUMNS: [&str; 8] = [
+    "description",
+    "query",
+    "limit",
+    "site-language",
+    "restrict",
+    "selected-languages",
+    "match-partial",
+    "tags",
+];
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TestTable {
+    path: PathBuf,
+    delimiter: Option<char>,
+}
+
+impl TestTable {
+    fn delimiter(&self) -> Result<u8> {
+        match self.delimiter {
+            Some(delimiter) if delimiter.is_ascii() => Ok(delimiter as u8),
+            Some(delimiter) => Err(anyhow!(
+                "Delimiter `{delimiter}` must be a single ASCII character"
+            )),
+            None if self
+                .path
+                .extension()
+                .is_some_and(|extension| extension == "tsv") =>
+            {
+                Ok(b'\t')
+            }
+            None => Ok(b','),
+        }
+    }
+
+    fn load(&self, suite_path: &Path) -> Result<Vec<TestCaseDetails>> {
+        let path = suite_path
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(&self.path);
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("Error reading test table {}", path.display()))?;
+        parse(&path, source.as_str(), self.delimiter()?)
+            .with_context(|| format!("Error in test table {}", path.display()))
+    }
+}
+
+pub fn load_test_tables(suite_path: &Path, test_suite: &mut TestSuite) -> Result<()> {
+    for test_table in test_suite.test_tables() {
+        let details = test_table.load(suite_path)?;
+        test_suite.add_test_details(details);
+    }
+    Ok(())
+}
+
+fn parse(path: &Path, source: &str, delimiter: u8) -> Result<Vec<TestCaseDetails>> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut reader = ReaderBuilder::new()
+        .delimiter(delimiter)
+        .from_reader(source.as_bytes());
+    let headers = reader
+        .headers()
+        .context("Error reading header row")?
+        .clone();
+    if let Some(header) = headers.iter().find(|header| !is_column(header)) {
+        let location = SourceLocation::new(path, source, 0);
+        return Err(location.error(format!("Unknown column `{header}`")));
+    }
+    let mut test_details = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let record = record.context("Error reading row")?;
+        let (row, byte) = record
+            .position()
+            .map(|position| (position.line(), position.byte()))
+            .unwrap_or_default();
+        let location = SourceLocation::new(path, source, byte as usize);
+        match test_case(&headers, &record, name.as_str(), row) {
+            Ok(details) => test_details.push(TestCaseDetails {
+                location: Some(location),
+                origin: Origin::TestTable,
+                ..details
+            }),
+            Err(error) => errors.push(location.error(format!("{error:#}"))),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(test_details),
+        false => Err(combine(errors)),
+    }
+}
+
+fn test_case(
+    headers: &StringRecord,
+    record: &StringRecord,
+    name: &str,
+    row: u64,
+) -> Result<TestCaseDetails> {
+    let mut table = Table::new();
+    let mut expected = Table::new();
+    for (header, cell) in headers.iter().zip(record.iter()) {
+        let cell = cell.trim();
+        if cell.is_empty() {
+            continue;
+        }
+        let key = column(header);
+        match EXPECTED.contains(&key) {
+            true => expected.insert(String::from(key), value(key, cell)?),
+            false => table.insert(String::from(key), value(key, cell)?),
+        };
+    }
+    if !expected.is_empty() {
+        table.insert(String::from("expected"), Value::Table(expected));
+    }
+    let description = match table.get("description").and_then(Value::as_str) {
+        Some(description) => format!("{description} ({name} row {row})"),
+        None => {
+            let query = table
+                .get("query")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            format!("{name} row {row}: {query}")
+        }
+    };
+    table.insert(String::from("description"), Value::String(description));
+    Value::Table(table)
+        .try_into()
+        .context("Error converting row to a test case")
+}
+
+fn column(header: &str) -> &str {
+    header.trim().trim_start_matches("expected.")
+}
+
+fn is_column(header: &str) -> bool {
+    let key = column(header);
+    EXPECTED.contains(&key) || COLUMNS.contains(&key)
+}
+
+fn value(key: &str, cell: &str) -> Result<Value> {
+    match key {
+        "limit" | "min-rank" => cell
+            .parse::<i64>()
+            .map(Value::Integer)
+            .with_context(|| format!("`{key}` must be a number but found `{cell}`")),
+        "match-partial" => cell
+            .parse::<bool>()
+            .map(Value::Boolean)
+            .with_context(|| format!("`{key}` must be true or false but found `{cell}`")),
+        "selected-languages" | "tags" => Ok(Value::Array(
+            cell.split(|character: char| character == ',' || character.is_whitespace())
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(String::from(item)))
+                .collect(),
+        )),
+        _ => Ok(Value::String(String::from(cell))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::defaults::Defaults;
+    use crate::expected::Expected;
+    use crate::identifiers::{SearchResultKey, SuttaplexUid};
+    use crate::test_case::TestCase;
+    use tempfile::TempDir;
+
+    fn defaults() -> Defaults {
+        Defaults {
+            limit: Some(10),
+            site_language: Some(String::from("en")),
+            restrict: Some(String::from("all")),
+            selected_languages: Some(vec![String::from("en"), String::from("pli")]),
+            match_partial: Some(false),
+            tags: Vec::new(),
+        }
+    }
+
+    fn test_cases(source: &str, delimiter: u8) -> Result<Vec<TestCase>> {
+        parse(Path::new("suttaplex.csv"), source, delimiter)?
+            .iter()
+            .map(|details| TestCase::new(&defaults(), details))
+            .collect()
+    }
+
+    #[test]
+    fn rows_become_test_cases() {
+        let test_cases = test_cases(
+            "query,expected.suttaplex,min-rank,selected-languages\n\
+            mn1,mn1,1,\n\
+            root of all things,mn1,,en de\n",
+            b',',
+        )
+        .unwrap();
+
+        assert_eq!(test_cases[0].description, "suttaplex.csv row 2: mn1");
+        assert_eq!(
+            test_cases[0].expected,
+            Some(Expected::Ranked {
+                key: SearchResultKey::Suttaplex {
+                    uid: SuttaplexUid::from("mn1")
+                },
+                min_rank: 1
+            })
+        );
+        assert_eq!(test_cases[0].selected_languages, vec!["en", "pli"]);
+        assert_eq!(
+            test_cases[1].description,
+            "suttaplex.csv row 3: root of all things"
+        );
+        assert_eq!(
+            test_cases[1].expected,
+            Some(Expected::Unranked {
+                key: SearchResultKey::Suttaplex {
+                    uid: SuttaplexUid::from("mn1")
+                }
+            })
+        );
+        assert_eq!(test_cases[1].selected_languages, vec!["en", "de"]);
+    }
+
+    #[test]
+    fn tab_separated_with_descriptions() {
+        let test_cases = test_cases(
+            "description\tquery\tsuttaplex\tmatch-partial\n\
+            Partial match\tmett\tsn46.54\ttrue\n",
+            b'\t',
+        )
+        .unwrap();
+
+        assert_eq!(
+            test_cases[0].description,
+            "Partial match (suttaplex.csv row 2)"
+        );
+        assert!(test_cases[0].match_partial);
+    }
+
+    #[test]
+    fn error_shows_row() {
+        let error = test_cases(
+            "query,limit\n\
+            mn1,10\n\
+            mn2,ten\n",
+            b',',
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            format!("{error:#}"),
+            "suttaplex.csv:3:1: `limit` must be a number but found `ten`: invalid digit found in string\n  |\n3 | mn2,ten\n  | ^"
+        );
+    }
+
+    #[test]
+    fn errors_from_every_row() {
+        let error = test_cases(
+            "query,limit,match-partial\n\
+            mn1,ten,false\n\
+            mn2,10,no\n",
+            b',',
+        )
+        .unwrap_err();
+
+        let message = format!("{error:#}");
+        assert!(message.starts_with("2 errors found\n\n"), "{message}");
+        assert!(message.contains("suttaplex.csv:2:1: `limit` must be a number"));
+        assert!(message.contains("suttaplex.csv:3:1: `match-partial` must be true or false"));
+    }
+
+    #[test]
+    fn error_for_unknown_column() {
+        let error = test_cases("query,author\nmn1,sujato\n", b',').unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "suttaplex.csv:1:1: Unknown column `author`\n  |\n1 | query,author\n  | ^"
+        );
+    }
+
+    #[test]
+    fn delimiter_from_extension() {
+        let table = |path: &str, delimiter: Option<char>| TestTable {
+            path: PathBuf::from(path),
+            delimiter,
+        };
+        assert_eq!(table("rows.csv", None).delimiter().unwrap(), b',');
+        assert_eq!(table("rows.tsv", None).delimiter().unwrap(), b'\t');
+        assert_eq!(table("rows.txt", Some(';')).delimiter().unwrap(), b';');
+        assert!(table("rows.txt", Some('→')).delimiter().is_err());
+    }
+
+    #[test]
+    fn load_table_next_to_suite() {
+        let directory = TempDir::new().unwrap();
+        std::fs::write(
+            directory.path().join("rows.csv"),
+            "query,suttaplex\nmn1,mn1\n",
+        )
+        .unwrap();
+        let suite_path = directory.path().join("suite.toml");
+        let mut test_suite = TestSuite::load_from_string(
+            r#"
+            [settings]
+            endpoint = "http://localhost/api/search/instant"
+
+            [defaults]
+            limit = 10
+            site-language = "en"
+            restrict = "all"
+            selected-languages = ["en", "pli"]
+            match-partial = false
+
+            [[test-case]]
+            description = "Find a sutta"
+            query = "metta"
+
+            [[test-table]]
+            path = "rows.csv"
+            "#,
+        )
+        .unwrap();
+
+        load_test_tables(&suite_path, &mut test_suite).unwrap();
+
+        let test_cases: Vec<(String, Origin)> = test_suite
+            .test_cases()
+            .map(|test_case| test_case.unwrap())
+            .map(|test_case| (test_case.description, test_case.origin))
+            .collect();
+        assert_eq!(
+            test_cases,
+            vec![
+                (String::from("Find a sutta"), Origin::Suite),
+                (String::from("rows.csv row 2: mn1"), Origin::TestTable)
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::search_error::{ErrorKind, SearchError};
 use anyhow::{Context, Result, anyhow};
 use http::StatusCode;
 use reqwest::blocking::Response;
@@ -6,13 +7,16 @@ use std::time::Duration;
 #[derive(Debug)]
 pub struct TimedResponse {
     pub json: Result<String>,
+    pub body: Option<String>,
     pub elapsed: Duration,
 }
 
 impl TimedResponse {
     pub fn new(elapsed: Duration, response: Result<Response>) -> TimedResponse {
+        let (json, body) = Self::json_and_body(response);
         TimedResponse {
-            json: Self::json(response),
+            json,
+            body,
             elapsed,
         }
     }
@@ -20,20 +24,33 @@ impl TimedResponse {
     fn check_status_code(code: StatusCode) -> Result<()> {
         match code {
             StatusCode::OK => Ok(()),
-            _ => Err(anyhow!(
-                "Expected status code to be {} but got {}",
-                StatusCode::OK,
-                code
-            )),
+            _ => Err(anyhow!(SearchError::new(
+                ErrorKind::HttpStatus,
+                format!(
+                    "Expected status code to be {} but got {}",
+                    StatusCode::OK,
+                    code
+                )
+            ))),
         }
     }
 
-    fn json(response: Result<Response>) -> Result<String> {
-        let response = response?;
-        Self::check_status_code(response.status())?;
-        response
-            .text()
-            .context("Could not obtain text body from HTTP response")
+    fn json_and_body(response: Result<Response>) -> (Result<String>, Option<String>) {
+        let response = match response {
+            Ok(response) => response,
+            Err(error) => return (Err(error), None),
+        };
+        match Self::check_status_code(response.status()) {
+            Ok(()) => {
+                let json = response.text().context(SearchError::new(
+                    ErrorKind::Transport,
+                    "Could not obtain text body from HTTP response",
+                ));
+                let body = json.as_ref().ok().cloned();
+                (json, body)
+            }
+            Err(error) => (Err(error), response.text().ok()),
+        }
     }
 }
 
@@ -49,6 +66,7 @@ mod tests {
             Err(anyhow!("Error sending HTTP request")),
         );
         assert_eq!(timed_response.elapsed, Duration::from_secs(1));
+        assert_eq!(timed_response.body, None);
         assert_eq!(
             timed_response.json.unwrap_err().to_string(),
             "Error sending HTTP request"
@@ -66,7 +84,13 @@ mod tests {
         let timed_response = TimedResponse::new(Duration::from_secs(1), Ok(http_response));
         assert_eq!(timed_response.elapsed, Duration::from_secs(1));
         assert_eq!(
-            timed_response.json.unwrap_err().to_string(),
+            timed_response.body,
+            Some(String::from("Internal server error"))
+        );
+        let error = timed_response.json.unwrap_err();
+        assert_eq!(ErrorKind::of(&error), ErrorKind::HttpStatus);
+        assert_eq!(
+            error.to_string(),
             "Expected status code to be 200 OK but got 500 Internal Server Error"
         );
     }
@@ -92,6 +116,7 @@ mod tests {
         let timed_response = TimedResponse::new(Duration::from_secs(1), Ok(http_response));
 
         assert_eq!(timed_response.elapsed, Duration::from_secs(1));
+        assert_eq!(timed_response.body, Some(String::from(json)));
         assert_eq!(timed_response.json.unwrap(), json);
     }
 }
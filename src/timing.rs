@@ -0,0 +1,207 @@
+use crate::test_record::TestRecord;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+const SLOWEST: usize = 5;
+const BUCKETS: u128 = 8;
+const BUCKET_ROUNDING_MS: u128 = 50;
+const BAR_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timings {
+    wall_clock: Duration,
+    tests: Vec<(String, u128)>,
+}
+
+impl Timings {
+    pub fn new(wall_clock: Duration, records: &[TestRecord]) -> Self {
+        Self {
+            wall_clock,
+            tests: records
+                .iter()
+                .map(|record| (record.description.clone(), record.elapsed_ms))
+                .collect(),
+        }
+    }
+
+    fn sorted(&self) -> Vec<u128> {
+        let mut elapsed: Vec<u128> = self.tests.iter().map(|(_, elapsed)| *elapsed).collect();
+        elapsed.sort();
+        elapsed
+    }
+
+    fn mean(&self) -> u128 {
+        match self.tests.len() {
+            0 => 0,
+            count => self.tests.iter().map(|(_, elapsed)| elapsed).sum::<u128>() / count as u128,
+        }
+    }
+
+    fn median(&self) -> u128 {
+        let sorted = self.sorted();
+        let middle = sorted.len() / 2;
+        match sorted.len() {
+            0 => 0,
+            count if count % 2 == 0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        }
+    }
+
+    fn slowest(&self) -> Vec<&(String, u128)> {
+        let mut tests: Vec<&(String, u128)> = self.tests.iter().collect();
+        tests.sort_by_key(|(_, elapsed)| std::cmp::Reverse(*elapsed));
+        tests.into_iter().take(SLOWEST).collect()
+    }
+
+    fn bucket_width(&self) -> u128 {
+        let slowest = self.sorted().last().copied().unwrap_or_default();
+        let width = (slowest / BUCKETS + 1).div_ceil(BUCKET_ROUNDING_MS) * BUCKET_ROUNDING_MS;
+        width.max(BUCKET_ROUNDING_MS)
+    }
+
+    fn histogram(&self) -> Vec<String> {
+        let width = self.bucket_width();
+        let sorted = self.sorted();
+        let buckets = sorted
+            .last()
+            .map(|slowest| slowest / width + 1)
+            .unwrap_or(0);
+        let counts: Vec<usize> = (0..buckets)
+            .map(|bucket| {
+                sorted
+                    .iter()
+                    .filter(|elapsed| **elapsed / width == bucket)
+                    .count()
+            })
+            .collect();
+        let most = counts.iter().max().copied().unwrap_or(1).max(1);
+        counts
+            .iter()
+            .enumerate()
+            .map(|(bucket, count)| {
+                let start = bucket as u128 * width;
+                let range = format!("{start}-{}ms", start + width - 1);
+                match count {
+                    0 => format!("  {range:>13}"),
+                    count => {
+                        let bar = "#".repeat((count * BAR_WIDTH).div_ceil(most));
+                        format!("  {range:>13} {bar} {count}")
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Total time {}ms, mean {}ms, median {}ms",
+            self.wall_clock.as_millis(),
+            self.mean(),
+            self.median()
+        )?;
+        writeln!(f)?;
+        writeln!(f, "Slowest tests")?;
+        for (description, elapsed) in self.slowest() {
+            let elapsed = format!("{elapsed}ms");
+            writeln!(f, "  {elapsed:6} {description}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Latency histogram")?;
+        for line in self.histogram() {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::Summary;
+
+    fn record(description: &str, elapsed_ms: u128) -> TestRecord {
+        TestRecord {
+            description: String::from(description),
+            summary: Summary::Passed,
+            rank: None,
+            top: Vec::new(),
+            elapsed_ms,
+        }
+    }
+
+    fn timings(elapsed: &[u128]) -> Timings {
+        let records: Vec<TestRecord> = elapsed
+            .iter()
+            .enumerate()
+            .map(|(index, elapsed)| record(format!("Test {}", index + 1).as_str(), *elapsed))
+            .collect();
+        Timings::new(Duration::from_millis(5000), &records)
+    }
+
+    #[test]
+    fn mean_and_median_of_odd_count() {
+        let timings = timings(&[300, 100, 800]);
+        assert_eq!(timings.mean(), 400);
+        assert_eq!(timings.median(), 300);
+    }
+
+    #[test]
+    fn median_of_even_count() {
+        assert_eq!(timings(&[100, 400, 200, 300]).median(), 250);
+    }
+
+    #[test]
+    fn no_tests() {
+        let timings = timings(&[]);
+        assert_eq!(timings.mean(), 0);
+        assert_eq!(timings.median(), 0);
+        assert!(timings.histogram().is_empty());
+    }
+
+    #[test]
+    fn five_slowest() {
+        let timings = timings(&[10, 60, 20, 50, 30, 40]);
+        let slowest: Vec<&str> = timings
+            .slowest()
+            .iter()
+            .map(|(description, _)| description.as_str())
+            .collect();
+        assert_eq!(
+            slowest,
+            vec!["Test 2", "Test 4", "Test 6", "Test 5", "Test 3"]
+        );
+    }
+
+    #[test]
+    fn bucket_width_is_rounded() {
+        assert_eq!(timings(&[30]).bucket_width(), 50);
+        assert_eq!(timings(&[1707]).bucket_width(), 250);
+    }
+
+    #[test]
+    fn display() {
+        let timings = timings(&[55, 679, 388, 120]);
+        assert_eq!(
+            timings.to_string(),
+            "Total time 5000ms, mean 310ms, median 254ms\n\
+            \n\
+            Slowest tests\n\
+            \x20 679ms  Test 2\n\
+            \x20 388ms  Test 3\n\
+            \x20 120ms  Test 4\n\
+            \x20 55ms   Test 1\n\
+            \n\
+            Latency histogram\n\
+            \x20        0-99ms ######################################## 1\n\
+            \x20     100-199ms ######################################## 1\n\
+            \x20     200-299ms\n\
+            \x20     300-399ms ######################################## 1\n\
+            \x20     400-499ms\n\
+            \x20     500-599ms\n\
+            \x20     600-699ms ######################################## 1\n"
+        );
+    }
+}